@@ -1,93 +1,271 @@
 use crate::{
-    change_type, changelog, config, entry,
+    change_type, changelog,
+    cli::AddArgs,
+    config, entry,
     errors::AddError,
-    github::{commit, extract_pr_info, get_git_info, get_open_pr, PRInfo},
+    github::{
+        commit, get_git_info, get_last_commit_subject, get_pr_info, is_offline, parse_pr_title,
+        PRInfo,
+    },
     inputs, release,
 };
-use std::borrow::BorrowMut;
+use std::{borrow::BorrowMut, path::Path};
 
 // Runs the logic to add an entry to the unreleased section of the changelog.
 //
 // After adding the new entry, the user is queried for a commit message to use
-// to commit the changes.
+// to commit the changes, unless committing is skipped.
 //
 // NOTE: the changes are NOT pushed to the origin when running the `add` command.
-pub async fn run(accept: bool) -> Result<(), AddError> {
+// In dry-run mode, the new entry is only printed without writing or committing it.
+pub async fn run(args: AddArgs) -> Result<(), AddError> {
     let config = config::load()?;
-    let git_info = get_git_info(&config)?;
+    let inputs = get_entry_inputs(&config, &args).await?;
+
+    let mut changelog = changelog::load(config.clone())?;
+    let new_entry = add_entry(
+        &config,
+        changelog.borrow_mut(),
+        inputs.change_type.as_str(),
+        inputs.category.as_str(),
+        inputs.description.as_str(),
+        inputs.number,
+        (!inputs.commit_type.is_empty()).then_some(inputs.commit_type.as_str()),
+    )?;
+
+    write_changelog(
+        &changelog,
+        inputs.change_type.as_str(),
+        &new_entry,
+        args.dry_run,
+    )?;
+    if args.dry_run {
+        return Ok(());
+    }
+
+    commit_entry(&args, &changelog.path, || {
+        let cm = inputs::get_commit_message(&config)?;
+        Ok(commit(&config, &cm, &args.co_authors, args.signoff)?)
+    })?;
+
+    Ok(())
+}
+
+/// Commits the added entry using the given commit function and returns whether
+/// the changes were committed.
+///
+/// NOTE: If committing is skipped, the changelog is left modified but unstaged.
+pub fn commit_entry<F>(args: &AddArgs, changelog_path: &Path, commit: F) -> Result<bool, AddError>
+where
+    F: FnOnce() -> Result<(), AddError>,
+{
+    if args.no_commit {
+        println!(
+            "added entry to {}; the changes are not staged or committed",
+            changelog_path.to_string_lossy()
+        );
+        return Ok(false);
+    }
+
+    commit()?;
+
+    Ok(true)
+}
+
+/// Gathers the inputs for the new entry from the given flags, the open pull request
+/// for the current branch and the user.
+///
+/// NOTE: Fields, that are provided as flags, are used without prompting the user.
+/// If all required fields are provided, the pull request is not queried at all.
+pub async fn get_entry_inputs(config: &config::Config, args: &AddArgs) -> Result<PRInfo, AddError> {
+    if let Some(inputs) = get_entry_inputs_from_args(config, args)? {
+        return Ok(inputs);
+    }
+
+    let accept = args.yes;
+    let git_info = get_git_info(config)?;
 
     let mut selectable_change_types: Vec<String> =
         config.change_types.clone().into_keys().collect();
     selectable_change_types.sort();
 
+    let offline = args.offline || is_offline();
     let retrieved: bool;
-    let pr_info = match get_open_pr(git_info).await {
-        Ok(i) => {
-            retrieved = true;
-            extract_pr_info(&config, &i)?
-        }
-        Err(_) => {
+    // NOTE: the information from the last commit is only used to pre-fill the prompts
+    let pr_info = match args.from_commit {
+        true => {
             retrieved = false;
-            PRInfo::default()
+            parse_pr_title(config, get_last_commit_subject()?.as_str())?
         }
+        false => match get_pr_info(config, git_info, offline).await {
+            Ok(i) => {
+                retrieved = !offline;
+                i
+            }
+            Err(_) => {
+                retrieved = false;
+                PRInfo::default()
+            }
+        },
     };
 
     let mut selected_change_type = pr_info.change_type.clone();
-    if !accept || !retrieved || !selectable_change_types.contains(&pr_info.change_type) {
+    if let Some(ct) = &args.change_type {
+        selected_change_type = get_configured_change_type(config, ct)?;
+    } else if !accept || !retrieved || !selectable_change_types.contains(&pr_info.change_type) {
         let ct_idx = selectable_change_types
             .iter()
             .position(|ct| ct.eq(&pr_info.change_type))
             .unwrap_or_default();
 
-        selected_change_type = inputs::get_change_type(&config, ct_idx)?;
+        selected_change_type = inputs::get_change_type(config, ct_idx)?;
     }
 
     let mut pr_number = pr_info.number;
-    if !accept || !retrieved {
+    if let Some(pr) = args.pr {
+        pr_number = pr;
+    } else if !accept || !retrieved {
         pr_number = inputs::get_pr_number(pr_info.number)?;
     }
 
     let mut cat = pr_info.category.clone();
-    if !accept || !retrieved || !config.categories.contains(&cat) {
+    if let Some(c) = &args.category {
+        cat = get_configured_category(config, c)?;
+    } else if config.use_categories && (!accept || !retrieved || !config.categories.contains(&cat))
+    {
         let cat_idx = config
             .categories
             .iter()
             .position(|c| c.eq(&pr_info.category))
             .unwrap_or_default();
 
-        cat = inputs::get_category(&config, cat_idx)?;
+        cat = inputs::get_category(config, cat_idx)?;
     }
 
     let mut desc = pr_info.description.clone();
-    if !accept || !retrieved {
+    if let Some(d) = &args.description {
+        desc.clone_from(d);
+    } else if !accept || !retrieved {
         desc = inputs::get_description(pr_info.description.as_str())?;
     }
 
-    let mut changelog = changelog::load(config.clone())?;
-    add_entry(
-        &config,
-        changelog.borrow_mut(),
-        selected_change_type.as_str(),
-        cat.as_str(),
-        desc.as_str(),
-        pr_number,
-    );
+    Ok(PRInfo {
+        change_type: selected_change_type,
+        category: cat,
+        commit_type: pr_info.commit_type,
+        description: desc,
+        number: pr_number,
+    })
+}
 
-    changelog.write(&changelog.path)?;
+/// Returns the entry inputs if all required fields are provided as flags.
+///
+/// NOTE: The category is only required if categories are used.
+pub fn get_entry_inputs_from_args(
+    config: &config::Config,
+    args: &AddArgs,
+) -> Result<Option<PRInfo>, AddError> {
+    let change_type = args
+        .change_type
+        .as_deref()
+        .map(|ct| get_configured_change_type(config, ct))
+        .transpose()?;
+    let category = args
+        .category
+        .as_deref()
+        .map(|c| get_configured_category(config, c))
+        .transpose()?;
+
+    let (Some(change_type), Some(description), Some(number)) =
+        (change_type, args.description.clone(), args.pr)
+    else {
+        return Ok(None);
+    };
 
-    let cm = inputs::get_commit_message(&config)?;
-    Ok(commit(&config, &cm)?)
+    if config.use_categories && category.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(PRInfo {
+        change_type,
+        category: category.unwrap_or_default(),
+        commit_type: String::default(),
+        description,
+        number,
+    }))
+}
+
+/// Returns the configured change type for the given name or abbreviation.
+fn get_configured_change_type(config: &config::Config, value: &str) -> Result<String, AddError> {
+    config
+        .change_types
+        .iter()
+        .find(|(name, short)| name.as_str() == value || short.as_str() == value)
+        .map(|(name, _)| name.to_string())
+        .ok_or(AddError::InvalidChangeType(value.to_string()))
+}
+
+/// Returns the given category if it is configured.
+fn get_configured_category(config: &config::Config, value: &str) -> Result<String, AddError> {
+    match config.categories.iter().any(|c| c == value) {
+        true => Ok(value.to_string()),
+        false => Err(AddError::InvalidCategory(value.to_string())),
+    }
+}
+
+/// Writes the changelog with the added entry to its path.
+///
+/// NOTE: In dry-run mode, only the new entry and its change type are printed.
+pub fn write_changelog(
+    changelog: &changelog::Changelog,
+    change_type: &str,
+    new_entry: &entry::Entry,
+    dry_run: bool,
+) -> Result<(), AddError> {
+    if dry_run {
+        println!("{change_type}: {}", new_entry.fixed);
+        return Ok(());
+    }
+
+    Ok(changelog.write(&changelog.path)?)
 }
 
 /// Adds the given contents into a new entry in the unreleased section
-/// of the changelog.
+/// of the changelog and returns the added entry.
+///
+/// NOTE: If the change types are configured as a closed set, only
+/// configured change types are accepted.
 pub fn add_entry(
     config: &config::Config,
     changelog: &mut changelog::Changelog,
     change_type: &str,
     cat: &str,
     desc: &str,
-    pr: u16,
+    pr: u64,
+    commit_type: Option<&str>,
+) -> Result<entry::Entry, AddError> {
+    if config.closed_change_types && !config.change_types.contains_key(change_type) {
+        return Err(AddError::InvalidChangeType(change_type.to_string()));
+    }
+
+    let new_entry = entry::Entry::new(config, cat, desc, pr, commit_type);
+    // NOTE: we're re-parsing the entry from the fixed version to incorporate all possible fixes
+    let new_fixed_entry = entry::parse(config, new_entry.fixed.as_str()).unwrap();
+
+    insert_entry(changelog, change_type, new_fixed_entry.clone());
+
+    Ok(new_fixed_entry)
+}
+
+/// Inserts the given entry at the top of the given change type in the
+/// unreleased section of the changelog.
+///
+/// NOTE: If there is no unreleased section or no section for the change type yet,
+/// they are created.
+pub fn insert_entry(
+    changelog: &mut changelog::Changelog,
+    change_type: &str,
+    new_entry: entry::Entry,
 ) {
     let unreleased = match changelog.releases.iter_mut().find(|r| r.is_unreleased()) {
         Some(r) => r,
@@ -109,15 +287,11 @@ pub fn add_entry(
         }
     }
 
-    let new_entry = entry::Entry::new(config, cat, desc, pr);
-    // NOTE: we're re-parsing the entry from the fixed version to incorporate all possible fixes
-    let new_fixed_entry = entry::parse(config, new_entry.fixed.as_str()).unwrap();
-
     // Get the mutable change type to add the entry into.
     // NOTE: If it's not found yet, we add a new section to the changelog.
     match change_type_is_found {
         false => {
-            let new_ct = change_type::new(change_type.to_owned(), Some(vec![new_fixed_entry]));
+            let new_ct = change_type::new(change_type.to_owned(), Some(vec![new_entry]));
             unreleased.change_types.push(new_ct);
         }
         true => {
@@ -126,7 +300,7 @@ pub fn add_entry(
                 .get_mut(idx)
                 .expect("failed to get change type");
 
-            mut_ct.entries.insert(0, new_fixed_entry);
+            mut_ct.entries.insert(0, new_entry);
         }
     }
 }
@@ -0,0 +1,110 @@
+use crate::errors::BaselineError;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+/// Holds the known problems of a changelog, that should be ignored when linting.
+///
+/// NOTE: The problems are stored as hashes of the file path and the problem message,
+/// so that they remain stable when the line numbers change.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Baseline {
+    pub problems: Vec<String>,
+}
+
+impl Baseline {
+    /// Creates a new baseline from the given list of problems.
+    pub fn new<T: AsRef<str>>(problems: &[T]) -> Baseline {
+        let mut hashes: Vec<String> = problems.iter().map(|p| hash_problem(p.as_ref())).collect();
+        hashes.sort();
+
+        Baseline { problems: hashes }
+    }
+
+    /// Exports the baseline to the given file path.
+    pub fn write(&self, path: &Path) -> Result<(), BaselineError> {
+        Ok(fs::write(path, serde_json::to_string_pretty(self)?)?)
+    }
+
+    /// Returns the problems, that are not contained in the baseline.
+    ///
+    /// NOTE: Each entry in the baseline only matches a single problem, so that
+    /// additional occurrences of a known problem are still reported.
+    pub fn filter_new_problems<T: AsRef<str> + Clone>(&self, problems: &[T]) -> Vec<T> {
+        let mut known = self.problems.clone();
+
+        problems
+            .iter()
+            .filter(|p| {
+                let hash = hash_problem(p.as_ref());
+                match known.iter().position(|k| k.eq(&hash)) {
+                    Some(idx) => {
+                        known.remove(idx);
+                        false
+                    }
+                    None => true,
+                }
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Loads the baseline from the given file path.
+pub fn load(path: &Path) -> Result<Baseline, BaselineError> {
+    Ok(serde_json::from_str(fs::read_to_string(path)?.as_str())?)
+}
+
+/// Returns a stable hash for the given problem, that is based on the
+/// file path and problem message but ignores the line number.
+pub fn hash_problem(problem: &str) -> String {
+    let problem_regex =
+        Regex::new(r"^(?P<path>.+?):\d+(:\d+)?: (?P<problem>.*)$").expect("invalid regex");
+
+    let key = match problem_regex.captures(problem) {
+        Some(c) => format!("{}:{}", &c["path"], &c["problem"]),
+        None => problem.to_string(),
+    };
+
+    format!("{:016x}", fnv1a(key.as_bytes()))
+}
+
+/// Implements the 64-bit FNV-1a hash, which is stable across platforms
+/// and Rust versions in contrast to the default hasher of the standard library.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod baseline_tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_ignores_line_number() {
+        assert_eq!(
+            hash_problem("CHANGELOG.md:10: invalid change category: (abc)"),
+            hash_problem("CHANGELOG.md:25: invalid change category: (abc)"),
+        );
+    }
+
+    #[test]
+    fn test_hash_depends_on_path_and_message() {
+        let hash = hash_problem("CHANGELOG.md:10: invalid change category: (abc)");
+        assert_ne!(
+            hash,
+            hash_problem("OTHER.md:10: invalid change category: (abc)")
+        );
+        assert_ne!(
+            hash,
+            hash_problem("CHANGELOG.md:10: invalid change category: (def)")
+        );
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    }
+}
@@ -1,12 +1,14 @@
 use crate::entry::Entry;
-use crate::{config, errors::ChangeTypeError};
+use crate::{config, errors::ChangeTypeError, problem::Problem};
 use regex::{Regex, RegexBuilder};
+use serde::Serialize;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ChangeType {
     pub name: String,
+    pub line: String,
     pub fixed: String,
-    pub problems: Vec<String>,
+    pub problems: Vec<Problem>,
     pub entries: Vec<Entry>,
 }
 
@@ -14,6 +16,7 @@ pub struct ChangeType {
 pub fn new(name: String, entries: Option<Vec<Entry>>) -> ChangeType {
     ChangeType {
         name: name.clone(),
+        line: format!("### {name}"),
         fixed: format!("### {name}"),
         problems: Vec::new(),
         entries: entries.unwrap_or_default(),
@@ -32,7 +35,7 @@ pub fn parse(config: config::Config, line: &str) -> Result<ChangeType, ChangeTyp
     // NOTE: calling unwrap here is okay, because the match was checked above
     let name = captures.name("name").unwrap().as_str();
     let mut fixed_name = name.to_string();
-    let mut problems: Vec<String> = Vec::new();
+    let mut problems: Vec<Problem> = Vec::new();
 
     // Check if the correctness of the current change type.
     if !config.change_types.iter().any(|(change_type, _)| {
@@ -55,26 +58,34 @@ pub fn parse(config: config::Config, line: &str) -> Result<ChangeType, ChangeTyp
         }
 
         if name != change_type {
-            problems.push(format!(
+            problems.push(Problem::warning(format!(
                 "'{change_type}' should be used instead of '{name}'"
-            ));
+            )));
             change_type.clone_into(&mut fixed_name);
         }
 
         true
     }) {
-        problems.push(format!("'{name}' is not a valid change type"))
+        // NOTE: unknown change types are only reported as problems if the set is not closed
+        if config.closed_change_types {
+            return Err(ChangeTypeError::UnknownChangeType(name.to_string()));
+        }
+
+        problems.push(Problem::error(format!(
+            "'{name}' is not a valid change type"
+        )))
     };
 
     let fixed = format!("### {fixed_name}");
     if format!("### {name}").ne(line) {
-        problems.push(format!(
+        problems.push(Problem::warning(format!(
             "Change type line is malformed; should be: '{fixed}'"
-        ));
+        )));
     }
 
     Ok(ChangeType {
         name: fixed_name,
+        line: line.to_string(),
         fixed,
         problems,
         entries: Vec::new(),
@@ -113,6 +124,18 @@ mod change_type_tests {
         );
     }
 
+    #[test]
+    fn test_fail_unknown_change_type_in_closed_set() {
+        let mut config = load_test_config();
+        config.closed_change_types = true;
+
+        let err = parse(config, "### Invalid Type").expect_err("expected parsing to fail");
+        assert_eq!(
+            err,
+            ChangeTypeError::UnknownChangeType("Invalid Type".to_string())
+        );
+    }
+
     #[test]
     fn test_fail_malformed_entry() {
         let example = "##jeaf";
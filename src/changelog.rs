@@ -1,5 +1,10 @@
-use crate::{change_type, config::Config, entry, errors::ChangelogError, escapes, release};
+use crate::{
+    change_type, config::Config, entry, errors::ChangelogError, escapes, problem::Problem, release,
+    version,
+};
+use chrono::NaiveDate;
 use regex::Regex;
+use serde::Serialize;
 use std::{
     collections::BTreeMap,
     fs,
@@ -7,42 +12,70 @@ use std::{
 };
 
 /// Represents the changelog contents.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Changelog {
     pub path: PathBuf,
+    // NOTE: the comments and legacy contents are not parsed, so they
+    // are intentionally omitted when serializing the changelog.
+    #[serde(skip)]
     comments: Vec<String>,
+    #[serde(skip)]
+    has_bom: bool,
+    /// Whether unresolved merge conflict markers were found in the changelog.
+    #[serde(skip)]
+    pub has_conflict_markers: bool,
+    #[serde(skip)]
     legacy_contents: Vec<String>,
+    #[serde(skip)]
+    line_ending: &'static str,
+    #[serde(skip)]
+    preamble: Vec<String>,
+    #[serde(skip)]
+    unreleased_placeholder: Option<String>,
     pub releases: Vec<release::Release>,
-    pub problems: Vec<String>,
+    pub problems: Vec<Problem>,
 }
 
 impl Changelog {
     /// Exports the changelog contents to the given filepath.
+    ///
+    /// NOTE: The changelog is not written if it contains merge conflict markers,
+    /// because the conflicting lines would otherwise be resolved silently.
     pub fn write(&self, export_path: &Path) -> Result<(), ChangelogError> {
+        if self.has_conflict_markers {
+            return Err(ChangelogError::ConflictMarkers);
+        }
+
         Ok(fs::write(export_path, self.get_fixed_contents())?)
     }
 
     /// Returns the fixed contents as a String to be exported.
     pub fn get_fixed_contents(&self) -> String {
-        let mut exported_string = "".to_string();
+        let mut exported_string = match self.has_bom {
+            true => "\u{feff}".to_string(),
+            false => "".to_string(),
+        };
 
         self.comments
             .iter()
             .for_each(|x| exported_string.push_str(format!("{x}\n").as_str()));
         exported_string.push_str("# Changelog\n");
 
-        for release in &self.releases {
-            exported_string.push('\n');
-            exported_string.push_str(release.fixed.as_str());
+        if !self.preamble.is_empty() {
             exported_string.push('\n');
+            self.preamble
+                .iter()
+                .for_each(|x| exported_string.push_str(format!("{x}\n").as_str()));
+        }
 
-            for change_type in &release.change_types {
-                exported_string.push('\n');
-                exported_string.push_str(change_type.fixed.as_str());
-                exported_string.push_str("\n\n");
+        for release in &self.releases {
+            exported_string.push('\n');
+            exported_string.push_str(release.get_fixed_contents().as_str());
 
-                for entry in &change_type.entries {
-                    exported_string.push_str(entry.fixed.as_str());
+            if let Some(placeholder) = &self.unreleased_placeholder {
+                if release.is_unreleased() && release.change_types.is_empty() {
+                    exported_string.push('\n');
+                    exported_string.push_str(placeholder.as_str());
                     exported_string.push('\n');
                 }
             }
@@ -52,52 +85,207 @@ impl Changelog {
             .iter()
             .for_each(|l| exported_string.push_str(format!("{}\n", l).as_str()));
 
-        exported_string
+        // NOTE: the contents are built with LF line endings and converted afterwards
+        // to preserve the line endings of the original file.
+        match self.line_ending {
+            "\n" => exported_string,
+            ending => exported_string.replace('\n', ending),
+        }
     }
 }
 
-/// Loads the changelog from the default changelog path.
+/// Loads the changelog from the configured changelog path.
 pub fn load(config: Config) -> Result<Changelog, ChangelogError> {
-    let changelog_file = match fs::read_dir(Path::new("./"))?.find(|e| {
-        e.as_ref()
-            .is_ok_and(|e| e.file_name().to_ascii_lowercase() == "changelog.md")
-    }) {
-        Some(f) => f.unwrap(),
-        None => {
+    let changelog_file = match find_changelog(&config, Path::new("./")) {
+        Ok(f) => f,
+        Err(e) => {
             println!("could not find the changelog in the current directory");
-            return Err(ChangelogError::NoChangelogFound);
+            return Err(e);
         }
     };
 
-    parse_changelog(config, changelog_file.path().as_path())
+    parse_changelog(config, changelog_file.as_path())
 }
 
-/// Parses the given changelog contents.
+/// Checks if the given release is out of order, i.e. if its version is not lower
+/// or its date is later than the ones of the previous release.
+/// The given previous version and date are updated with the values of the release.
+///
+/// NOTE: The unreleased section as well as unparsable versions or dates are skipped.
+fn is_out_of_order(
+    config: &Config,
+    release: &release::Release,
+    previous_version: &mut Option<version::Version>,
+    previous_date: &mut Option<NaiveDate>,
+) -> bool {
+    if release.is_unreleased() {
+        return false;
+    }
+
+    let mut out_of_order = false;
+
+    if let Ok(v) = version::parse(release.version.as_str()) {
+        if previous_version.as_ref().is_some_and(|p| !p.gt(&v)) {
+            out_of_order = true;
+        }
+        *previous_version = Some(v);
+    }
+
+    if let Some(d) = release
+        .date
+        .as_ref()
+        .and_then(|d| NaiveDate::parse_from_str(d, config.get_date_format()).ok())
+    {
+        if previous_date.is_some_and(|p| d > p) {
+            out_of_order = true;
+        }
+        *previous_date = Some(d);
+    }
+
+    out_of_order
+}
+
+/// Returns the path of the changelog in the given directory.
+///
+/// NOTE: The configured changelog path is used if it exists, otherwise
+/// the directory is searched for the changelog file.
+pub fn find_changelog(config: &Config, dir: &Path) -> Result<PathBuf, ChangelogError> {
+    let configured_path = dir.join(config.changelog_path.as_str());
+    if !config.changelog_path.is_empty() && configured_path.is_file() {
+        return Ok(configured_path);
+    }
+
+    find_changelog_in_dir(dir)
+}
+
+/// Searches the given directory for the changelog file and returns its path.
+///
+/// NOTE: The file name is matched case-insensitively.
+pub fn find_changelog_in_dir(dir: &Path) -> Result<PathBuf, ChangelogError> {
+    match fs::read_dir(dir)?.find(|e| {
+        e.as_ref()
+            .is_ok_and(|e| e.file_name().eq_ignore_ascii_case("changelog.md"))
+    }) {
+        Some(f) => Ok(f?.path()),
+        None => Err(ChangelogError::NoChangelogFound),
+    }
+}
+
+/// Applies the configured expected spelling for the given key to all entries
+/// in the changelog at the given path.
+///
+/// NOTE: Only the given spelling is fixed, all other lines remain unchanged.
+pub fn apply_spelling(config: &Config, file_path: &Path, key: &str) -> Result<(), ChangelogError> {
+    let mut spelling_config = config.clone();
+    spelling_config
+        .expected_spellings
+        .retain(|correct_spelling, _| correct_spelling.eq(key));
+
+    let contents = fs::read_to_string(file_path)?;
+    let line_ending = get_line_ending(contents.as_str());
+    let mut updated: String = contents
+        .lines()
+        .map(|line| match line.trim().starts_with('-') {
+            true => entry::check_spelling(&spelling_config, line).0,
+            false => line.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(line_ending);
+
+    if contents.ends_with('\n') {
+        updated.push_str(line_ending);
+    }
+
+    Ok(fs::write(file_path, updated)?)
+}
+
+/// Returns the line ending used in the given contents.
+///
+/// NOTE: Files are considered to use CRLF line endings if the first line ends with CRLF.
+fn get_line_ending(contents: &str) -> &'static str {
+    match contents.find('\n') {
+        Some(idx) if contents[..idx].ends_with('\r') => "\r\n",
+        _ => "\n",
+    }
+}
+
+/// Parses the changelog at the given path.
 pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, ChangelogError> {
     let contents = fs::read_to_string(file_path)?;
 
+    parse_changelog_str(config, contents.as_str(), file_path)
+}
+
+/// Parses the given changelog contents.
+///
+/// NOTE: The given path is not read but only used to reference
+/// the changelog in the found problems.
+pub fn parse_changelog_str(
+    config: Config,
+    contents: &str,
+    file_path: &Path,
+) -> Result<Changelog, ChangelogError> {
+    // NOTE: a leading byte order mark is removed for parsing and restored when exporting
+    let (contents, has_bom) = match contents.strip_prefix('\u{feff}') {
+        Some(c) => (c, true),
+        None => (contents, false),
+    };
+
     let mut n_releases = 0;
     let mut n_change_types = 0;
 
     let mut comments: Vec<String> = Vec::new();
     let mut legacy_contents: Vec<String> = Vec::new();
+    let mut preamble: Vec<String> = Vec::new();
     let mut releases: Vec<release::Release> = Vec::new();
-    let mut problems: Vec<String> = Vec::new();
+    let mut problems: Vec<Problem> = Vec::new();
+    let mut conflict_problems: Vec<Problem> = Vec::new();
 
     let mut current_release = release::new_empty_release();
     let mut seen_releases: Vec<String> = Vec::new();
     let mut current_change_type: change_type::ChangeType;
     let mut seen_change_types: Vec<String> = Vec::new();
-    let mut seen_prs: Vec<u16> = Vec::new();
+    let mut seen_prs: Vec<u64> = Vec::new();
+    let mut previous_version: Option<version::Version> = None;
+    let mut previous_date: Option<NaiveDate> = None;
 
     let mut escapes: Vec<escapes::LinterEscape> = Vec::new();
+    let mut is_change_type_escaped = false;
+    let mut is_after_entry = false;
+    let mut previous_line_kind: Option<LineKind> = None;
+    let mut n_blank_lines = 0;
     let mut is_comment = false;
     let mut is_legacy = false;
+    let mut has_title = false;
 
     let enter_comment_regex = Regex::new("<!--")?;
     let exit_comment_regex = Regex::new("-->")?;
+    let link_definition_regex = Regex::new(entry::LINK_DEFINITION_PATTERN)?;
+
+    // NOTE: the link definitions are collected upfront, because they are
+    // usually placed after the entries, that reference them.
+    let references: BTreeMap<String, String> = contents
+        .lines()
+        .filter_map(|l| entry::get_link_definition(&link_definition_regex, l))
+        .collect();
 
     for (i, line) in contents.lines().enumerate() {
+        // NOTE: conflict markers are reported first, because the surrounding contents
+        // cannot be parsed reliably.
+        if is_conflict_marker(line) {
+            add_to_problems(
+                &mut conflict_problems,
+                file_path,
+                i,
+                Problem::error(format!(
+                    "merge conflict marker found: '{}'",
+                    line.trim_end()
+                )),
+            );
+
+            continue;
+        }
+
         if is_legacy {
             legacy_contents.push(line.to_string());
             continue;
@@ -105,13 +293,29 @@ pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, Ch
 
         let trimmed_line = line.trim();
 
-        if enter_comment_regex.is_match(trimmed_line) {
+        // NOTE: entries already report trailing whitespace as part of their description checks,
+        // while headings are parsed without it to avoid reporting it twice.
+        let trimmed_end = line.trim_end();
+        let is_sub_item = is_after_entry && is_sub_item(line);
+        if trimmed_end.len() != line.len() && (is_sub_item || !trimmed_line.starts_with('-')) {
+            add_to_problems_with_column(
+                &mut problems,
+                file_path,
+                i,
+                trimmed_end.chars().count() + 1,
+                Problem::info("trailing whitespace"),
+            );
+        }
+
+        // NOTE: entry lines are not considered, so that they can carry
+        // inline annotations (e.g. the commit type).
+        if !trimmed_line.starts_with('-') && enter_comment_regex.is_match(trimmed_line) {
             is_comment = true;
         }
 
         if is_comment && exit_comment_regex.is_match(trimmed_line) {
             is_comment = false;
-            comments.push(line.to_string());
+            comments.push(trimmed_end.to_string());
 
             // Check inline comments
             if let Some(e) = escapes::check_escape_pattern(trimmed_line) {
@@ -122,12 +326,52 @@ pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, Ch
         }
 
         if is_comment {
-            comments.push(line.to_string());
+            comments.push(trimmed_end.to_string());
             continue;
         }
 
+        let line_kind = get_line_kind(trimmed_line);
+        if line_kind == LineKind::Blank {
+            n_blank_lines += 1;
+        } else {
+            if config.check_blank_lines {
+                if let Some(p) = check_blank_lines(previous_line_kind, line_kind, n_blank_lines) {
+                    add_to_problems(&mut problems, file_path, i, Problem::info(p));
+                }
+            }
+
+            previous_line_kind = Some(line_kind);
+            n_blank_lines = 0;
+        }
+
+        // NOTE: link definitions are exported at the end of the release they are found in,
+        // while definitions before the first release are kept as part of the preamble.
+        if link_definition_regex.is_match(trimmed_line) {
+            if let Some(r) = releases.last_mut() {
+                r.link_definitions.push(trimmed_end.to_string());
+                is_after_entry = false;
+
+                continue;
+            }
+        }
+
+        // NOTE: sub-bullets are attached to the preceding entry without further checks
+        if is_sub_item {
+            if let Some(e) = releases
+                .last_mut()
+                .and_then(|r| r.change_types.last_mut())
+                .and_then(|ct| ct.entries.last_mut())
+            {
+                e.sub_items.push(trimmed_end.to_string());
+            }
+
+            continue;
+        }
+        is_after_entry = false;
+
         if trimmed_line.starts_with("## ") {
-            current_release = release::parse(&config, line)?;
+            current_release = release::parse(&config, trimmed_end)?;
+            current_release.line_number = i + 1;
 
             releases.push(current_release.clone());
             n_releases += 1;
@@ -136,14 +380,32 @@ pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, Ch
                     &mut problems,
                     file_path,
                     i,
-                    format!("duplicate release: {}", &current_release.version),
+                    Problem::error(format!("duplicate release: {}", &current_release.version)),
                 );
             } else {
                 seen_releases.push((current_release.version).to_string());
+
+                if is_out_of_order(
+                    &config,
+                    &current_release,
+                    &mut previous_version,
+                    &mut previous_date,
+                ) {
+                    add_to_problems(
+                        &mut problems,
+                        file_path,
+                        i,
+                        Problem::error(format!(
+                            "release {} is out of order",
+                            &current_release.version
+                        )),
+                    );
+                }
             };
 
             // reset the seen change types for the current release
             seen_change_types.clear();
+            is_change_type_escaped = false;
             n_change_types = 0;
 
             if current_release
@@ -156,34 +418,56 @@ pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, Ch
             current_release
                 .problems
                 .into_iter()
-                .for_each(|p| add_to_problems(&mut problems, file_path, i, p.to_string()));
+                .for_each(|p| add_to_problems(&mut problems, file_path, i, p));
 
             continue;
         }
 
         if trimmed_line.starts_with("### ") {
-            current_change_type = change_type::parse(config.clone(), line)?;
+            if n_releases == 0 {
+                add_to_problems(
+                    &mut problems,
+                    file_path,
+                    i,
+                    Problem::error("change type found before any release section"),
+                );
+
+                // NOTE: the line is kept as is, so that it is not removed when fixing
+                preamble.push(trimmed_end.to_string());
+
+                continue;
+            }
+
+            current_change_type = change_type::parse(config.clone(), trimmed_end)?;
+
+            // NOTE: a change type escape covers the heading and all entries until the next heading
+            is_change_type_escaped = escapes.contains(&escapes::LinterEscape::ChangeType);
+            escapes.clear();
 
             n_change_types += 1;
-            if seen_change_types.contains(&current_change_type.name) {
+            if is_change_type_escaped {
+                seen_change_types.push(current_change_type.name.clone());
+            } else if seen_change_types.contains(&current_change_type.name) {
                 add_to_problems(
                     &mut problems,
                     file_path,
                     i,
-                    format!(
+                    Problem::error(format!(
                         "duplicate change type in release {}: {}",
                         current_release.version.clone(),
                         current_change_type.name.clone(),
-                    ),
+                    )),
                 )
             } else {
                 seen_change_types.push(current_change_type.name.clone());
             }
 
-            current_change_type
-                .problems
-                .iter()
-                .for_each(|p| add_to_problems(&mut problems, file_path, i, p.to_string()));
+            if !is_change_type_escaped {
+                current_change_type
+                    .problems
+                    .iter()
+                    .for_each(|p| add_to_problems(&mut problems, file_path, i, p.clone()));
+            }
 
             let last_release = releases
                 .get_mut(n_releases - 1)
@@ -194,15 +478,37 @@ pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, Ch
             continue;
         }
 
-        if !trimmed_line.starts_with('-') {
+        // NOTE: any prose between the title and the first release is kept as is,
+        // including bullet points, that are not valid entries.
+        if n_releases == 0
+            && (!trimmed_line.starts_with('-')
+                || entry::parse_with_references(&config, line, &references).is_err())
+        {
+            match !has_title && trimmed_line.starts_with("# ") {
+                true => has_title = true,
+                false => preamble.push(trimmed_end.to_string()),
+            }
+
+            continue;
+        }
+
+        if !trimmed_line.starts_with('-')
+            || config
+                .unreleased_placeholder
+                .as_ref()
+                .is_some_and(|p| p.trim() == trimmed_line)
+        {
             continue;
         }
 
-        let current_entry = match entry::parse(&config, line) {
+        let is_line_escaped =
+            is_change_type_escaped || escapes.contains(&escapes::LinterEscape::FullLine);
+
+        let current_entry = match entry::parse_with_references(&config, line, &references) {
             Ok(e) => e,
             Err(err) => {
-                if !escapes.contains(&escapes::LinterEscape::FullLine) {
-                    add_to_problems(&mut problems, file_path, i, err.to_string());
+                if !is_line_escaped {
+                    add_to_problems(&mut problems, file_path, i, Problem::error(err.to_string()));
                 }
 
                 // reset escapes after processing entry
@@ -212,31 +518,65 @@ pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, Ch
             }
         };
 
-        if seen_prs.contains(&current_entry.pr_number)
-            && (!escapes.contains(&escapes::LinterEscape::DuplicatePR)
-                && !escapes.contains(&escapes::LinterEscape::FullLine))
-        {
+        let is_duplicate_escaped =
+            is_line_escaped || escapes.contains(&escapes::LinterEscape::DuplicatePR);
+        for pr_number in &current_entry.pr_numbers {
+            if !seen_prs.contains(pr_number) {
+                seen_prs.push(*pr_number);
+            } else if !is_duplicate_escaped {
+                add_to_problems(
+                    &mut problems,
+                    file_path,
+                    i,
+                    Problem::error(format!("duplicate PR: #{}", pr_number)),
+                );
+            }
+        }
+
+        if !is_line_escaped {
+            for (idx, p) in current_entry.problems.iter().enumerate() {
+                match current_entry.problem_columns.get(&idx) {
+                    Some(col) => {
+                        add_to_problems_with_column(&mut problems, file_path, i, *col, p.clone())
+                    }
+                    None => add_to_problems(&mut problems, file_path, i, p.clone()),
+                }
+            }
+        }
+
+        let Some(last_release) = releases.last_mut() else {
             add_to_problems(
                 &mut problems,
                 file_path,
                 i,
-                format!("duplicate PR: #{}", &current_entry.pr_number,),
+                Problem::error("entry found before any release section"),
             );
-            escapes.retain(|e| e.ne(&escapes::LinterEscape::DuplicatePR));
-        } else {
-            seen_prs.push(current_entry.pr_number)
-        }
+            escapes.clear();
 
-        if !escapes.contains(&escapes::LinterEscape::FullLine) {
-            current_entry
-                .problems
-                .iter()
-                .for_each(|p| add_to_problems(&mut problems, file_path, i, p.to_string()));
-        }
+            // NOTE: the line is kept as is, so that it is not removed when fixing
+            preamble.push(trimmed_end.to_string());
+
+            continue;
+        };
+
+        // NOTE: entries outside of a change type are moved into a default change type
+        // when fixing the changelog.
+        if n_change_types == 0 {
+            add_to_problems(
+                &mut problems,
+                file_path,
+                i,
+                Problem::error(format!(
+                    "entry found outside of a change type in release {}",
+                    last_release.version
+                )),
+            );
 
-        let last_release = releases
-            .get_mut(n_releases - 1)
-            .expect("failed to get last release");
+            last_release
+                .change_types
+                .push(change_type::new(get_default_change_type(&config), None));
+            n_change_types += 1;
+        }
 
         let last_change_type = last_release
             .change_types
@@ -244,32 +584,206 @@ pub fn parse_changelog(config: Config, file_path: &Path) -> Result<Changelog, Ch
             .expect("failed to get last change type");
 
         last_change_type.entries.push(current_entry);
+        is_after_entry = true;
 
         // Reset the escapes after an entry line
         escapes.clear();
     }
 
+    if config.warn_empty_unreleased {
+        if let Some(unreleased) = releases.iter().find(|r| r.is_unreleased()) {
+            if unreleased
+                .change_types
+                .iter()
+                .all(|ct| ct.entries.is_empty())
+            {
+                add_to_problems(
+                    &mut problems,
+                    file_path,
+                    unreleased.line_number - 1,
+                    Problem::info("Unreleased section is empty"),
+                );
+            }
+        }
+    }
+
+    let has_conflict_markers = !conflict_problems.is_empty();
+    conflict_problems.append(&mut problems);
+    let problems = conflict_problems;
+
+    // NOTE: surrounding blank lines are not part of the preamble
+    while preamble.first().is_some_and(|l| l.trim().is_empty()) {
+        preamble.remove(0);
+    }
+    while preamble.last().is_some_and(|l| l.trim().is_empty()) {
+        preamble.pop();
+    }
+
     Ok(Changelog {
         path: file_path.to_path_buf(),
         releases,
         comments,
         problems,
+        has_bom,
+        has_conflict_markers,
         legacy_contents,
+        line_ending: get_line_ending(contents),
+        preamble,
+        unreleased_placeholder: config.unreleased_placeholder,
     })
 }
 
+/// Checks if the given line is a marker of an unresolved merge conflict.
+fn is_conflict_marker(line: &str) -> bool {
+    ["<<<<<<<", "|||||||", ">>>>>>>"]
+        .iter()
+        .any(|m| line.starts_with(m))
+        || line.trim_end() == "======="
+}
+
+/// Checks if the given line is an indented bullet point, which belongs
+/// to the preceding entry.
+fn is_sub_item(line: &str) -> bool {
+    (line.starts_with("  ") || line.starts_with('\t'))
+        && ["- ", "* "]
+            .iter()
+            .any(|b| line.trim_start().starts_with(b))
+}
+
+/// Represents the kinds of lines, that are relevant for the blank line checks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LineKind {
+    Blank,
+    Entry,
+    Heading,
+    Other,
+}
+
+fn get_line_kind(trimmed_line: &str) -> LineKind {
+    if trimmed_line.is_empty() {
+        LineKind::Blank
+    } else if trimmed_line.starts_with('#') {
+        LineKind::Heading
+    } else if trimmed_line.starts_with('-') {
+        LineKind::Entry
+    } else {
+        LineKind::Other
+    }
+}
+
+/// Returns a problem if the number of blank lines between the previous and the
+/// current line deviates from the layout of the fixed changelog, which has exactly
+/// one blank line around headings and none between entries.
+///
+/// NOTE: Comments are not considered, so the previous line is the last non-blank
+/// line outside of a comment.
+fn check_blank_lines(
+    previous: Option<LineKind>,
+    current: LineKind,
+    n_blank_lines: usize,
+) -> Option<&'static str> {
+    match (previous?, current) {
+        (_, LineKind::Heading) if n_blank_lines != 1 => {
+            Some("there should be exactly one blank line before a heading")
+        }
+        (LineKind::Heading, _) if n_blank_lines != 1 => {
+            Some("there should be exactly one blank line after a heading")
+        }
+        (LineKind::Entry, LineKind::Entry) if n_blank_lines != 0 => {
+            Some("there should be no blank lines between entries")
+        }
+        _ => None,
+    }
+}
+
+/// Returns the change type to use for entries, that are found outside
+/// of a change type section.
+///
+/// NOTE: This is `Improvements` if configured, otherwise the first configured change type.
+fn get_default_change_type(config: &Config) -> String {
+    match config.change_types.contains_key("Improvements") {
+        true => "Improvements".to_string(),
+        false => config
+            .change_types
+            .keys()
+            .next()
+            .cloned()
+            .unwrap_or("Improvements".to_string()),
+    }
+}
+
 /// Used for formatting the problem statements in the changelog.
 ///
 /// NOTE: The line ID will be incremented by one based on the loop enumeration where it is used.
-fn add_to_problems(problems: &mut Vec<String>, fp: &Path, line: usize, problem: impl Into<String>) {
-    problems.push(format!(
-        "{}:{}: {}",
-        fp.to_string_lossy(),
-        line + 1,
-        problem.into()
+fn add_to_problems(problems: &mut Vec<Problem>, fp: &Path, line: usize, problem: Problem) {
+    problems.push(Problem::new(
+        problem.severity,
+        format!("{}:{}: {}", fp.to_string_lossy(), line + 1, problem.message),
     ))
 }
 
+fn add_to_problems_with_column(
+    problems: &mut Vec<Problem>,
+    fp: &Path,
+    line: usize,
+    column: usize,
+    problem: Problem,
+) {
+    problems.push(Problem::new(
+        problem.severity,
+        format!(
+            "{}:{}:{}: {}",
+            fp.to_string_lossy(),
+            line + 1,
+            column,
+            problem.message
+        ),
+    ))
+}
+
+// Tries to parse the individual entries of an existing changelog
+// to derive a configuration from it.
+//
+// NOTE: Errors while parsing are ignored as the purpose of this method
+// is to simply extract all available information.
+pub fn get_settings_from_existing_changelog(config: &mut Config, contents: &str) {
+    let mut seen_change_types: Vec<String> = Vec::new();
+    let mut seen_categories: Vec<String> = Vec::new();
+
+    for line in contents.lines() {
+        let trimmed_line = line.trim();
+
+        if trimmed_line.starts_with("### ") {
+            if let Ok(ct) = change_type::parse(config.clone(), line) {
+                if !seen_change_types.contains(&ct.name) {
+                    seen_change_types.push(ct.name)
+                }
+            };
+
+            continue;
+        }
+
+        if let Ok(e) = entry::parse(config, line) {
+            if !e.category.is_empty() && !seen_categories.contains(&e.category) {
+                seen_categories.push(e.category)
+            }
+        }
+    }
+
+    let mut change_types: BTreeMap<String, String> = BTreeMap::new();
+    seen_change_types.into_iter().for_each(|ct| {
+        let pattern = regex::Regex::new(r"\s+")
+            .unwrap()
+            .replace_all(ct.as_str(), "\\s*")
+            .to_ascii_lowercase();
+        change_types.insert(ct, pattern);
+    });
+
+    seen_categories.sort();
+    config.categories = seen_categories;
+    config.change_types = change_types;
+}
+
 #[cfg(test)]
 mod changelog_tests {
     use std::str::FromStr;
@@ -295,7 +809,12 @@ mod changelog_tests {
             path: PathBuf::from_str("test").unwrap(),
             releases: Vec::new(),
             comments: Vec::new(),
+            has_bom: false,
+            has_conflict_markers: false,
             legacy_contents: Vec::new(),
+            line_ending: "\n",
+            preamble: Vec::new(),
+            unreleased_placeholder: None,
             problems: Vec::new(),
         };
         let e = entry::parse(&cfg, example).expect("failed to parse entry");
@@ -317,7 +836,7 @@ mod changelog_tests {
         assert_eq!(
             mut_cr
                 .change_types
-                .get(0)
+                .first()
                 .expect("failed to get first change type in assert")
                 .entries
                 .len(),
@@ -325,57 +844,167 @@ mod changelog_tests {
         );
         assert_eq!(
             cl.releases
-                .get(0)
+                .first()
                 .expect("failed to get first release")
                 .change_types
-                .get(0)
+                .first()
                 .expect("failed to get first change type in changelog")
                 .entries
                 .len(),
             1
         );
     }
-}
 
-// Tries to parse the individual entries of an existing changelog
-// to derive a configuration from it.
-//
-// NOTE: Errors while parsing are ignored as the purpose of this method
-// is to simply extract all available information.
-pub fn get_settings_from_existing_changelog(config: &mut Config, contents: &str) {
-    let mut seen_change_types: Vec<String> = Vec::new();
-    let mut seen_categories: Vec<String> = Vec::new();
+    #[test]
+    fn test_parse_changelog_str() {
+        let contents = concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (cli) [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix aPi.\n\n",
+            "## [v3.0.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v3.0.0) - 2024-04-27\n\n",
+            "### Features\n\n",
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Add initial Python implementation.\n",
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Add duplicate entry.\n",
+        );
 
-    for line in contents.lines() {
-        let trimmed_line = line.trim();
+        let cl = parse_changelog_str(load_test_config(), contents, Path::new("virtual.md"))
+            .expect("failed to parse changelog contents");
+        assert_eq!(cl.path, PathBuf::from("virtual.md"));
+        assert_eq!(cl.releases.len(), 2);
+        assert_eq!(
+            cl.problems,
+            vec![
+                "virtual.md:7: 'API' should be used instead of 'aPi'",
+                "virtual.md:14: duplicate PR: #1",
+            ]
+        );
+    }
 
-        if trimmed_line.starts_with("### ") {
-            if let Ok(ct) = change_type::parse(config.clone(), line) {
-                if !seen_change_types.contains(&ct.name) {
-                    seen_change_types.push(ct.name)
-                }
-            };
+    #[test]
+    fn test_comment_starting_mid_line() {
+        let contents = concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (cli) [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix API. <!-- clu-commit-type: fix -->\n\n",
+            "### Features <!--\n",
+            "- (cli) #3 legacy style entry\n",
+            "-->\n",
+        );
 
-            continue;
-        }
+        let cl = parse_changelog_str(load_test_config(), contents, Path::new("virtual.md"))
+            .expect("failed to parse changelog contents");
+        assert!(cl.problems.is_empty(), "{:?}", cl.problems);
+        assert_eq!(cl.releases[0].change_types[0].entries.len(), 1);
+    }
 
-        if let Ok(e) = entry::parse(config, line) {
-            if !seen_categories.contains(&e.category) {
-                seen_categories.push(e.category)
-            }
-        }
+    #[test]
+    fn test_escape_change_type() {
+        let contents = concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "<!-- clu-disable-next-change-type: legacy section -->\n",
+            "### Improvements\n\n",
+            "- [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) fixed the aPi\n",
+            "- (cli) #3 legacy style entry\n\n",
+            "## [v3.0.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v3.0.0) - 2024-04-27\n\n",
+            "### Features\n\n",
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Add initial Python implementation.\n",
+        );
+
+        let cl = parse_changelog_str(load_test_config(), contents, Path::new("virtual.md"))
+            .expect("failed to parse changelog contents");
+        assert!(cl.problems.is_empty(), "{:?}", cl.problems);
     }
 
-    let mut change_types: BTreeMap<String, String> = BTreeMap::new();
-    seen_change_types.into_iter().for_each(|ct| {
-        let pattern = regex::Regex::new(r"\s+")
-            .unwrap()
-            .replace_all(ct.as_str(), "\\s*")
-            .to_ascii_lowercase();
-        change_types.insert(ct, pattern);
-    });
+    #[test]
+    fn test_escape_change_type_ends_at_next_heading() {
+        let contents = concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "<!-- clu-disable-next-change-type -->\n",
+            "### Improvements\n\n",
+            "- [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) fixed the aPi\n\n",
+            "### Bug Fixes\n\n",
+            "- (cli) [#3](https://github.com/MalteHerrmann/changelog-utils/pull/3) Fix aPi.\n",
+        );
 
-    seen_categories.sort();
-    config.categories = seen_categories;
-    config.change_types = change_types;
+        let cl = parse_changelog_str(load_test_config(), contents, Path::new("virtual.md"))
+            .expect("failed to parse changelog contents");
+        assert_eq!(
+            cl.problems,
+            vec!["virtual.md:12: 'API' should be used instead of 'aPi'"]
+        );
+    }
+
+    #[test]
+    fn test_preamble_survives_fix() {
+        let contents = concat!(
+            "# Changelog\n\n",
+            "All notable changes to this project will be documented in this file.\n\n",
+            "The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/).\n\n",
+            "## Unreleased\n\n",
+            "###  bug fixes\n\n",
+            "- (cli) [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix aPi.\n",
+        );
+
+        let cl = parse_changelog_str(load_test_config(), contents, Path::new("virtual.md"))
+            .expect("failed to parse changelog contents");
+        assert_eq!(
+            cl.get_fixed_contents(),
+            concat!(
+                "# Changelog\n\n",
+                "All notable changes to this project will be documented in this file.\n\n",
+                "The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/).\n\n",
+                "## Unreleased\n\n",
+                "### Bug Fixes\n\n",
+                "- (cli) [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix API.\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_preamble_keeps_intro_verbatim() {
+        let contents = concat!(
+            "# Changelog\n\n",
+            "# Guiding Principles\n\n",
+            "- Changelogs are for humans.\n",
+            "- The latest version comes first.\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (cli) [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix API.\n",
+        );
+
+        let cl = parse_changelog_str(load_test_config(), contents, Path::new("virtual.md"))
+            .expect("failed to parse changelog contents");
+        assert!(cl.problems.is_empty(), "{:?}", cl.problems);
+        assert_eq!(cl.get_fixed_contents(), contents);
+    }
+
+    #[test]
+    fn test_conflict_markers() {
+        let contents = concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "<<<<<<< HEAD\n",
+            "- (cli) [#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix aPi.\n",
+            "=======\n",
+            "- (cli) [#3](https://github.com/MalteHerrmann/changelog-utils/pull/3) Fix the linter.\n",
+            ">>>>>>> feature-branch\n",
+        );
+
+        let cl = parse_changelog_str(load_test_config(), contents, Path::new("virtual.md"))
+            .expect("failed to parse changelog contents");
+        assert_eq!(
+            cl.problems,
+            vec![
+                "virtual.md:7: merge conflict marker found: '<<<<<<< HEAD'",
+                "virtual.md:9: merge conflict marker found: '======='",
+                "virtual.md:11: merge conflict marker found: '>>>>>>> feature-branch'",
+                "virtual.md:8: 'API' should be used instead of 'aPi'",
+            ]
+        );
+    }
 }
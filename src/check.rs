@@ -0,0 +1,117 @@
+use crate::{changelog, config, errors::CheckError};
+use std::{fmt, path::Path, process::Command};
+
+/// Represents the outcome of an individual setup check.
+#[derive(Debug, PartialEq)]
+pub enum CheckStatus {
+    Pass(String),
+    Warn(String),
+    Fail(String),
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckStatus::Pass(msg) => write!(f, "[ok]   {msg}"),
+            CheckStatus::Warn(msg) => write!(f, "[warn] {msg}"),
+            CheckStatus::Fail(msg) => write!(f, "[fail] {msg}"),
+        }
+    }
+}
+
+/// Runs the logic to check the setup of the changelog utilities
+/// in the current working directory.
+pub fn run() -> Result<(), CheckError> {
+    let results = run_checks(&std::env::current_dir()?);
+    results.iter().for_each(|r| println!("{r}"));
+
+    let n_failed = results
+        .iter()
+        .filter(|r| matches!(r, CheckStatus::Fail(_)))
+        .count();
+
+    match n_failed {
+        0 => Ok(()),
+        n => Err(CheckError::Failed(n)),
+    }
+}
+
+/// Runs all setup checks for the given directory.
+pub fn run_checks(dir: &Path) -> Vec<CheckStatus> {
+    let (config_status, config) = check_config(dir);
+
+    vec![
+        config_status,
+        check_changelog(dir, config.as_ref()),
+        check_git(),
+        check_github_token(std::env::var("GITHUB_TOKEN").ok().as_deref()),
+    ]
+}
+
+/// Checks that a configuration is present in the given directory and can be parsed.
+/// The configuration is returned if it could be loaded.
+pub fn check_config(dir: &Path) -> (CheckStatus, Option<config::Config>) {
+    let config_path = config::get_config_path(dir);
+    if !config_path.exists() {
+        return (
+            CheckStatus::Fail("no configuration found, run `clu init` to create one".into()),
+            None,
+        );
+    }
+
+    match config::load_from_path(&config_path) {
+        Ok(c) => (
+            CheckStatus::Pass(format!(
+                "configuration found at {}",
+                config_path.to_string_lossy()
+            )),
+            Some(c),
+        ),
+        Err(e) => (
+            CheckStatus::Fail(format!(
+                "failed to load configuration at {}: {e}",
+                config_path.to_string_lossy()
+            )),
+            None,
+        ),
+    }
+}
+
+/// Checks that the changelog exists in the given directory.
+///
+/// NOTE: If a configuration is given, the configured changelog path is used.
+pub fn check_changelog(dir: &Path, config: Option<&config::Config>) -> CheckStatus {
+    let found = match config {
+        Some(c) => changelog::find_changelog(c, dir),
+        None => changelog::find_changelog_in_dir(dir),
+    };
+
+    match found {
+        Ok(p) => CheckStatus::Pass(format!("changelog found at {}", p.to_string_lossy())),
+        Err(e) => CheckStatus::Fail(format!("failed to find changelog: {e}")),
+    }
+}
+
+/// Checks that the `git` binary is available.
+pub fn check_git() -> CheckStatus {
+    match Command::new("git").arg("--version").output() {
+        Ok(o) if o.status.success() => CheckStatus::Pass(format!(
+            "{} is available",
+            String::from_utf8_lossy(&o.stdout).trim()
+        )),
+        _ => CheckStatus::Fail("git is not available".into()),
+    }
+}
+
+/// Checks that the given GitHub token is set.
+///
+/// NOTE: A missing token is only a warning, because unauthenticated requests
+/// are sufficient to read public repositories.
+pub fn check_github_token(token: Option<&str>) -> CheckStatus {
+    match token {
+        Some(t) if !t.is_empty() => CheckStatus::Pass("GITHUB_TOKEN is set".into()),
+        _ => CheckStatus::Warn(
+            "GITHUB_TOKEN is not set, only public repositories can be accessed".into(),
+        ),
+    }
+}
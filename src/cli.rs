@@ -1,36 +1,282 @@
+use crate::{dedupe::Keep, get::ReleaseFormat, init::Preset, problem::Severity};
 use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 pub enum ChangelogCLI {
     #[command(about = "Adds a new entry to the unreleased section of the changelog")]
     Add(AddArgs),
+    #[command(about = "Checks the setup of the changelog utilities in the current directory")]
+    Check,
     #[command(
         about = "Creates a PR in the configured target repository and adds the corresponding changelog entry"
     )]
-    CreatePR,
+    CreatePR(CreatePRArgs),
+    #[command(about = "Removes entries, that reference the same PR as another entry")]
+    Dedupe(DedupeArgs),
     #[command(about = "Applies all possible auto-fixes to the changelog")]
-    Fix,
+    Fix(FixArgs),
+    #[command(about = "Prints the contents of the given release")]
+    Get(GetArgs),
     #[command(about = "Checks if the changelog contents adhere to the defined rules")]
-    Lint,
+    Lint(LintArgs),
     #[command(about = "Initializes the changelog configuration in the current directory")]
     #[command(long_about = r#"
 Initializes the changelog configuration in the current directory.
 It creates an empty changelog skeleton if no existing changelog is found as well as a default configuration for the tool.
 "#)]
-    Init,
+    Init(InitArgs),
+    #[command(about = "Creates a CI workflow, that lints the changelog in pull requests")]
+    InitCI(InitCIArgs),
     #[command(subcommand)]
     #[command(
         about = "Adjust the changelog configuration like allowed categories, change types or other"
     )]
     Config(ConfigSubcommands),
+    #[command(about = "Prints the absolute path of the changelog or the configuration file")]
+    Path(PathArgs),
+    #[command(about = "Proposes the categories of merged PRs, that are not configured yet")]
+    LearnCategories,
+    #[command(about = "Moves the entry with the given PR number to another change type")]
+    Move(MoveArgs),
     #[command(about = "Turns the Unreleased section into a new release with the given version")]
     Release(ReleaseArgs),
+    #[command(about = "Removes the entry with the given PR number from the unreleased section")]
+    Remove(RemoveArgs),
+    #[command(about = "Prints a summary of the changelog contents")]
+    Stats(StatsArgs),
+    #[command(
+        about = "Checks that the PRs linked in the changelog exist in the target repository"
+    )]
+    VerifyLinks(VerifyLinksArgs),
 }
 
-#[derive(Args, Debug)]
+#[derive(Args, Debug, Default)]
 pub struct AddArgs {
     #[arg(short, long)]
     pub yes: bool,
+    #[arg(long, help = "The change type (name or abbreviation) of the new entry")]
+    pub change_type: Option<String>,
+    #[arg(long, help = "The category of the new entry")]
+    pub category: Option<String>,
+    #[arg(long, help = "The description of the new entry")]
+    pub description: Option<String>,
+    #[arg(long, value_name = "NUMBER", help = "The PR number of the new entry")]
+    pub pr: Option<u64>,
+    #[arg(
+        long = "co-author",
+        value_name = "NAME <EMAIL>",
+        help = "Add a Co-authored-by trailer to the commit (can be used multiple times)"
+    )]
+    pub co_authors: Vec<String>,
+    #[arg(long, help = "Add a Signed-off-by trailer to the commit")]
+    pub signoff: bool,
+    #[arg(
+        long,
+        help = "Only print the new entry and its change type without writing or committing it"
+    )]
+    pub dry_run: bool,
+    #[arg(long, help = "Write the new entry without committing the changes")]
+    pub no_commit: bool,
+    #[arg(
+        long,
+        help = "Skip the GitHub requests and only rely on the local information and user input (also enabled with CLU_OFFLINE=1)"
+    )]
+    pub offline: bool,
+    #[arg(
+        long,
+        help = "Pre-fill the change type, category and description from the last commit subject"
+    )]
+    pub from_commit: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CreatePRArgs {
+    #[arg(
+        long = "co-author",
+        value_name = "NAME <EMAIL>",
+        help = "Add a Co-authored-by trailer to the commit (can be used multiple times)"
+    )]
+    pub co_authors: Vec<String>,
+    #[arg(long, help = "Add a Signed-off-by trailer to the commit")]
+    pub signoff: bool,
+    #[arg(long, help = "Create the pull request as a draft without asking")]
+    pub draft: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct FixArgs {
+    #[arg(short, long, help = "Ask for confirmation before applying each fix")]
+    pub interactive: bool,
+    #[arg(
+        long,
+        conflicts_with = "interactive",
+        help = "Only print the changes, that would be applied, without writing them"
+    )]
+    pub check: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "check"],
+        help = "Print the fixed changelog to stdout instead of writing it"
+    )]
+    pub stdout: bool,
+    #[arg(
+        long,
+        help = "The date to add to released sections, that are missing a date"
+    )]
+    pub date: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct GetArgs {
+    #[arg(
+        required_unless_present_any = ["all", "since", "unreleased"],
+        conflicts_with_all = ["all", "since", "unreleased"],
+        help = "The version of the release to print"
+    )]
+    pub version: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["all", "unreleased"],
+        help = "Print all releases newer than the given version"
+    )]
+    pub since: Option<String>,
+    #[arg(long, conflicts_with = "unreleased", help = "Print all releases")]
+    pub all: bool,
+    #[arg(
+        long,
+        requires = "all",
+        help = "Print the whole parsed changelog as JSON"
+    )]
+    pub json: bool,
+    #[arg(long, help = "Print the unreleased section")]
+    pub unreleased: bool,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ReleaseFormat::Markdown,
+        help = "The output format of the printed releases"
+    )]
+    pub format: ReleaseFormat,
+    #[arg(
+        long,
+        help = "Remove the categories from the entries when printing release notes"
+    )]
+    pub strip_categories: bool,
+    #[arg(long, help = "Do not pipe long output through the pager")]
+    pub no_pager: bool,
+}
+
+#[derive(Args, Debug, Default)]
+pub struct LintArgs {
+    #[arg(long, help = "Only print the given number of problems")]
+    pub max_problems: Option<usize>,
+    #[arg(long, help = "Group the found problems by file")]
+    pub group: bool,
+    #[arg(
+        long,
+        conflicts_with = "write_baseline",
+        help = "Only report problems that are not contained in the given baseline file"
+    )]
+    pub baseline: Option<PathBuf>,
+    #[arg(long, help = "Write the current problems to the given baseline file")]
+    pub write_baseline: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "Only report problems in releases from the given version on"
+    )]
+    pub from: Option<String>,
+    #[arg(
+        long,
+        help = "Only report problems in releases up to the given version"
+    )]
+    pub to: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        help = "Only fail if there are problems with at least the given severity"
+    )]
+    pub fail_on: Severity,
+}
+
+#[derive(Args, Debug)]
+pub struct PathArgs {
+    #[arg(
+        long,
+        conflicts_with = "config",
+        help = "Print the path of the changelog (default)"
+    )]
+    pub changelog: bool,
+    #[arg(long, help = "Print the path of the configuration file")]
+    pub config: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct InitArgs {
+    #[arg(long, help = "Use a preset for the initial configuration")]
+    pub preset: Option<Preset>,
+}
+
+#[derive(Args, Debug)]
+#[group(required = true, multiple = false)]
+pub struct InitCIArgs {
+    #[arg(long, help = "Create a GitHub Actions workflow")]
+    pub github: bool,
+    #[arg(long, help = "Create a GitLab CI configuration")]
+    pub gitlab: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct MoveArgs {
+    #[arg(help = "The PR number of the entry to move")]
+    pub pr_number: u64,
+    #[arg(help = "The change type to move the entry into")]
+    pub change_type: String,
+}
+
+#[derive(Args, Debug)]
+pub struct RemoveArgs {
+    #[arg(help = "The PR number of the entry to remove")]
+    pub pr_number: u64,
+}
+
+#[derive(Args, Debug)]
+pub struct DedupeArgs {
+    #[arg(
+        long,
+        value_enum,
+        help = "The entry to keep for each duplicate PR (prompts for each PR if not set)"
+    )]
+    pub keep: Option<Keep>,
+}
+
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    #[arg(long, help = "Print the statistics as JSON")]
+    pub json: bool,
+    #[arg(
+        long,
+        help = "Count the entries per PR author using the GitHub API (uses GITHUB_TOKEN if set)"
+    )]
+    pub by_author: bool,
+    #[arg(
+        long,
+        requires = "by_author",
+        default_value = "Unreleased",
+        help = "The release to count the entries per author for"
+    )]
+    pub release: String,
+    #[arg(long, help = "Do not pipe long output through the pager")]
+    pub no_pager: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ShowArgs {
+    #[arg(long, help = "Only print the value of the given setting")]
+    pub key: Option<String>,
+    #[arg(long, help = "Do not pipe long output through the pager")]
+    pub no_pager: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -41,14 +287,18 @@ pub enum ConfigSubcommands {
         about = "Adjust the allowed change types within releases (like 'Bug Fixes', 'Features', etc.)"
     )]
     ChangeType(KeyValueArgs),
+    #[command(about = "Prints the JSON Schema of the configuration file")]
+    ExportSchema,
     #[command(about = "Set or unset the optional legacy version")]
     LegacyVersion(ConditionalArgs),
     #[command(about = "Shows the current configuration")]
-    Show,
+    Show(ShowArgs),
     #[command(about = "Adjust the expected spellings that should be enforced in the changelog")]
-    Spelling(KeyValueArgs),
+    Spelling(SpellingArgs),
     #[command(about = "Sets the target repository for the changelog entries")]
     TargetRepo(StringValue),
+    #[command(about = "Checks the configuration for inconsistencies")]
+    Validate,
 }
 
 #[derive(Args, Debug)]
@@ -65,6 +315,8 @@ pub struct ConfigArgs {
 #[derive(Args, Debug)]
 pub struct ReleaseArgs {
     pub version: Option<String>,
+    #[arg(long, help = "The date of the release (defaults to today)")]
+    pub date: Option<String>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -89,6 +341,27 @@ pub enum KeyValueOperation {
     Remove { key: String },
 }
 
+#[derive(Args, Debug)]
+pub struct SpellingArgs {
+    #[command(subcommand)]
+    pub command: SpellingOperation,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SpellingOperation {
+    #[command(about = "Adds a new expected spelling with the pattern of possible misspellings")]
+    Add { key: String, value: String },
+    #[command(about = "Removes an expected spelling if it is found in the configuration")]
+    Remove { key: String },
+    #[command(about = "Renames the expected spelling while keeping its pattern")]
+    Rename {
+        old: String,
+        new: String,
+        #[arg(long, help = "Apply the new spelling to the entries in the changelog")]
+        update_changelog: bool,
+    },
+}
+
 #[derive(Args, Debug)]
 pub struct ConditionalArgs {
     #[command(subcommand)]
@@ -102,3 +375,9 @@ pub enum OptionalOperation {
     #[command(about = "Unsets the optional value")]
     Unset,
 }
+
+#[derive(Args, Debug, Default)]
+pub struct VerifyLinksArgs {
+    #[arg(long, help = "Only verify the links in the Unreleased section")]
+    pub unreleased_only: bool,
+}
@@ -1,47 +1,120 @@
 use crate::{
+    changelog,
     cli::{
         CategoryOperation, ConfigSubcommands,
-        ConfigSubcommands::{Category, ChangeType, LegacyVersion, Show, Spelling, TargetRepo},
-        KeyValueOperation, OptionalOperation,
+        ConfigSubcommands::{
+            Category, ChangeType, ExportSchema, LegacyVersion, Show, Spelling, TargetRepo, Validate,
+        },
+        KeyValueOperation, OptionalOperation, SpellingOperation,
     },
-    config, errors,
+    config, config_schema, errors, pager,
 };
-use std::path::Path;
+use std::{collections::BTreeMap, path::Path};
 
 // Handles the CLI subcommands to adjust the configuration file.
 pub fn adjust_config(config_subcommand: ConfigSubcommands) -> Result<(), errors::CLIError> {
-    let mut configuration = config::load()?;
-
+    // NOTE: the read-only subcommands are handled before reading the configuration file,
+    // because they do not require an existing or a complete configuration.
     match config_subcommand {
-        Category(args) => match args.command {
-            CategoryOperation::Add { value } => config::add_category(&mut configuration, value)?,
-            CategoryOperation::Remove { value } => {
-                config::remove_category(&mut configuration, value)?
-            }
-        },
-        ChangeType(args) => match args.command {
-            KeyValueOperation::Add { key, value } => {
-                config::add_into_collection(&mut configuration.change_types, key, value)?
+        ExportSchema => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&config_schema::get_schema())
+                    .expect("failed to serialize schema")
+            );
+            return Ok(());
+        }
+        Show(args) => {
+            let loaded = config::load()?;
+            let output = match args.key {
+                Some(key) => config::get_value(&loaded, key.as_str())?,
+                None => loaded.to_string(),
+            };
+            pager::print(output.as_str(), args.no_pager);
+            return Ok(());
+        }
+        Validate => {
+            let problems = config::validate(&config::load()?);
+            if problems.is_empty() {
+                println!("configuration is valid");
+                return Ok(());
             }
-            KeyValueOperation::Remove { key } => {
-                config::remove_from_collection(&mut configuration.change_types, key)?
+
+            problems.iter().for_each(|p| println!("{}", p));
+            return Err(errors::ConfigError::Invalid(problems.len()).into());
+        }
+        _ => (),
+    }
+
+    // NOTE: the adjustments are applied to the fields of the configuration file itself
+    // without merging the parent configurations it extends.
+    let config_path = config::get_config_path(Path::new("./"));
+    let mut configuration = config::read_partial_from_path(&config_path)?;
+    let mut renamed_spelling: Option<String> = None;
+
+    match config_subcommand {
+        Category(args) => {
+            let categories = configuration.categories.get_or_insert_with(Vec::new);
+            match args.command {
+                CategoryOperation::Add { value } => config::add_category(categories, value)?,
+                CategoryOperation::Remove { value } => config::remove_category(categories, value)?,
             }
-        },
-        Show => println!("{}", configuration),
-        Spelling(args) => match args.command {
-            KeyValueOperation::Add { key, value } => {
-                config::add_into_collection(&mut configuration.expected_spellings, key, value)?
+        }
+        ChangeType(args) => {
+            let change_types = configuration.change_types.get_or_insert_with(BTreeMap::new);
+            match args.command {
+                KeyValueOperation::Add { key, value } => {
+                    config::add_into_collection(change_types, key, value)?
+                }
+                KeyValueOperation::Remove { key } => {
+                    config::remove_from_collection(change_types, key)?
+                }
             }
-            KeyValueOperation::Remove { key } => {
-                config::remove_from_collection(&mut configuration.expected_spellings, key)?
+        }
+        Spelling(args) => {
+            let expected_spellings = configuration
+                .expected_spellings
+                .get_or_insert_with(BTreeMap::new);
+            match args.command {
+                SpellingOperation::Add { key, value } => {
+                    config::add_expected_spelling(expected_spellings, key, value)?
+                }
+                SpellingOperation::Remove { key } => {
+                    config::remove_from_collection(expected_spellings, key)?
+                }
+                SpellingOperation::Rename {
+                    old,
+                    new,
+                    update_changelog,
+                } => {
+                    config::rename_in_collection(expected_spellings, old, new.clone())?;
+
+                    if update_changelog {
+                        renamed_spelling = Some(new);
+                    }
+                }
             }
-        },
+        }
         LegacyVersion(args) => match args.command {
             OptionalOperation::Set { value } => configuration.legacy_version = Some(value),
             OptionalOperation::Unset => configuration.legacy_version = None,
         },
-        TargetRepo(args) => config::set_target_repo(&mut configuration, args.value)?,
+        TargetRepo(args) => {
+            configuration.target_repo =
+                Some(config::get_valid_target_repo(&config::load()?, args.value)?)
+        }
+        // NOTE: these are handled before reading the configuration file.
+        ExportSchema | Show(_) | Validate => unreachable!(),
+    }
+
+    configuration.export(&config_path)?;
+
+    // NOTE: the changelog is updated with the merged configuration including the renamed spelling
+    if let Some(new) = renamed_spelling {
+        let loaded = config::load()?;
+        let changelog_path = changelog::find_changelog(&loaded, Path::new("./"))?;
+        changelog::apply_spelling(&loaded, &changelog_path, &new)?;
     }
 
-    Ok(configuration.export(Path::new(".clconfig.json"))?)
+    Ok(())
 }
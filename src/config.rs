@@ -1,7 +1,20 @@
-use crate::errors::{ConfigAdjustError, ConfigError};
+use crate::{
+    entry::compile_regex,
+    errors::{ConfigAdjustError, ConfigError},
+    version,
+};
+use chrono::{
+    format::{Item, StrftimeItems},
+    NaiveDate,
+};
 use serde::{Deserialize, Serialize};
 use serde_json;
-use std::{collections::BTreeMap, fmt, fs, path::Path};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    fs,
+    path::{Path, PathBuf},
+};
 use url::Url;
 
 /// Holds the configuration of the application
@@ -16,11 +29,34 @@ pub struct Config {
     /// an abbreviation that is to be used as a short form
     /// in pull request titles.
     pub change_types: BTreeMap<String, String>,
+    /// Whether to report blank lines around headings and entries,
+    /// that deviate from the layout of the fixed changelog.
+    #[serde(default)]
+    pub check_blank_lines: bool,
+    /// Whether the configured change types are a closed set.
+    ///
+    /// Note: If enabled, unknown change types cause the linter to fail
+    /// instead of being reported as problems and they cannot be added.
+    #[serde(default)]
+    pub closed_change_types: bool,
     /// The default commit message to be used when committing
     /// the new changelog entry.
     pub commit_message: String,
+    /// The list of trailers, that are added to the commit message when
+    /// committing a new changelog entry (e.g. `Signed-off-by: Jane Doe <jane@example.com>`).
+    #[serde(default)]
+    pub commit_trailers: Vec<String>,
     /// The relative path of the changelog file.
     pub changelog_path: String,
+    /// Optional date format of the releases (e.g. `%d.%m.%Y`).
+    ///
+    /// Note: If not set, ISO dates like `2024-04-27` are expected.
+    #[serde(default)]
+    pub date_format: Option<String>,
+    /// Whether the `#` in PR links is expected to be escaped (e.g. `[\#1](...)`),
+    /// which is required by some Markdown renderers.
+    #[serde(default)]
+    pub escape_pr_hash: bool,
     /// The map of expected spellings.
     ///
     /// Note: The key is the correct spelling and the value
@@ -28,19 +64,303 @@ pub struct Config {
     /// (mis-)spellings, that should be associated with the correct
     /// version.
     pub expected_spellings: BTreeMap<String, String>,
+    /// Optional path of a parent configuration, that this configuration
+    /// is merged onto (e.g. a shared configuration in a monorepo).
+    ///
+    /// Note: Relative paths are resolved from the directory of this configuration.
+    #[serde(default)]
+    pub extends: Option<String>,
+    /// The kind of forge, that hosts the target repository.
+    ///
+    /// Note: This determines the expected paths of PR and release links.
+    #[serde(default)]
+    pub forge: ForgeKind,
+    /// Optional host of the forge instance (e.g. `github.mycorp.com`
+    /// for GitHub Enterprise or a self-hosted GitLab instance).
+    ///
+    /// Note: Defaults to `github.com` or `gitlab.com` depending on the forge if not set.
+    #[serde(default)]
+    pub github_host: Option<String>,
+    /// The list of words or phrases, that must not occur in
+    /// entry descriptions (e.g. "WIP" or internal codenames).
+    #[serde(default)]
+    pub forbidden_terms: Vec<String>,
+    /// The list of words, that are not allowed as the first word
+    /// of an entry description, to enforce the use of imperative mood
+    /// (e.g. "Added" or "Fixes").
+    #[serde(default)]
+    pub imperative_blocklist: Vec<String>,
+    /// The optional map of GitHub PR labels to change type abbreviations,
+    /// that is used if the PR title does not contain a valid change type.
+    ///
+    /// Note: The key is the label name (e.g. `type: bug`) and the value
+    /// is the abbreviation of a configured change type (e.g. `fix`).
+    #[serde(default)]
+    pub label_change_type_map: BTreeMap<String, String>,
+    /// Whether release headers without the version in brackets
+    /// (e.g. `## v1.2.0`) are accepted and reported as problems instead of
+    /// failing to parse the changelog.
+    #[serde(default)]
+    pub lenient_releases: bool,
+    /// The maximum number of attempts for GitHub API calls, that are
+    /// rejected because of rate limits.
+    #[serde(default = "default_max_github_attempts")]
+    pub max_github_attempts: u32,
+    /// Optional maximum number of characters for entry descriptions.
+    ///
+    /// Note: Markdown links in the description are counted with their
+    /// text only.
+    #[serde(default)]
+    pub max_description_length: Option<usize>,
+    /// Optional base URL of a mirror repository (e.g. on GitLab).
+    ///
+    /// Note: If set, entries are expected to contain an additional link to
+    /// the corresponding merge request in the mirror after the PR link.
+    #[serde(default)]
+    pub mirror_repo: Option<String>,
     /// Optional Version to specify legacy entries, that
     /// don't need to adhere to the given linter standards.
     ///
     /// TODO: use Version type directly instead
     pub legacy_version: Option<String>,
+    /// Additional characters, that are accepted after a matched spelling,
+    /// e.g. `,;:)`. By default, only whitespace and periods are accepted.
+    #[serde(default)]
+    pub spelling_boundary: String,
     /// The target repository, that represents the base url
     /// enforced to occur in PR links.
     pub target_repo: String,
+    /// Optional placeholder line, that is inserted below an empty
+    /// Unreleased section, e.g. `_No unreleased changes yet._`.
+    #[serde(default)]
+    pub unreleased_placeholder: Option<String>,
+    /// Whether entries are expected to contain a category,
+    /// e.g. `- (cli) [#1](...) ...`.
+    #[serde(default = "default_true")]
+    pub use_categories: bool,
+    /// Whether to report an Unreleased section without any entries.
+    #[serde(default)]
+    pub warn_empty_unreleased: bool,
+}
+
+/// Holds the fields of a configuration, that extends a parent configuration.
+///
+/// NOTE: All fields are optional, so that only the overridden fields
+/// have to be contained in the configuration file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PartialConfig {
+    pub categories: Option<Vec<String>>,
+    pub change_types: Option<BTreeMap<String, String>>,
+    pub check_blank_lines: Option<bool>,
+    pub closed_change_types: Option<bool>,
+    pub commit_message: Option<String>,
+    pub commit_trailers: Option<Vec<String>>,
+    pub changelog_path: Option<String>,
+    pub date_format: Option<String>,
+    pub escape_pr_hash: Option<bool>,
+    pub expected_spellings: Option<BTreeMap<String, String>>,
+    pub extends: Option<String>,
+    pub forge: Option<ForgeKind>,
+    pub github_host: Option<String>,
+    pub forbidden_terms: Option<Vec<String>>,
+    pub imperative_blocklist: Option<Vec<String>>,
+    pub label_change_type_map: Option<BTreeMap<String, String>>,
+    pub lenient_releases: Option<bool>,
+    pub max_github_attempts: Option<u32>,
+    pub max_description_length: Option<usize>,
+    pub mirror_repo: Option<String>,
+    pub legacy_version: Option<String>,
+    pub spelling_boundary: Option<String>,
+    pub target_repo: Option<String>,
+    pub unreleased_placeholder: Option<String>,
+    pub use_categories: Option<bool>,
+    pub warn_empty_unreleased: Option<bool>,
+}
+
+/// Represents the supported forges to host the target repository.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    #[default]
+    GitHub,
+    GitLab,
+}
+
+impl ForgeKind {
+    /// Returns the path segment of links to pull or merge requests.
+    pub fn pr_path(&self) -> &str {
+        match self {
+            ForgeKind::GitHub => "pull",
+            ForgeKind::GitLab => "-/merge_requests",
+        }
+    }
+
+    /// Returns the path segment of links to releases.
+    pub fn release_path(&self) -> &str {
+        match self {
+            ForgeKind::GitHub => "releases/tag",
+            ForgeKind::GitLab => "-/tags",
+        }
+    }
+
+    /// Returns the host of the public instance of the forge.
+    pub fn default_host(&self) -> &str {
+        match self {
+            ForgeKind::GitHub => "github.com",
+            ForgeKind::GitLab => "gitlab.com",
+        }
+    }
+
+    /// Returns the name of the page, that release links point to.
+    pub fn release_page_name(&self) -> &str {
+        match self {
+            ForgeKind::GitHub => "GitHub release",
+            ForgeKind::GitLab => "GitLab tag",
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_github_attempts() -> u32 {
+    3
 }
 
 impl Config {
+    /// Exports the configuration to the given path.
+    ///
+    /// NOTE: The configuration is written as TOML if the path has a `.toml` extension
+    /// and as JSON otherwise.
     pub fn export(&self, path: &Path) -> Result<(), ConfigError> {
-        Ok(fs::write(path, format!("{}", self))?)
+        let contents = match is_toml(path) {
+            true => toml::to_string_pretty(self)?,
+            false => format!("{}", self),
+        };
+
+        Ok(fs::write(path, contents)?)
+    }
+
+    /// Overlays the fields, that are set in the given partial configuration,
+    /// onto this configuration.
+    ///
+    /// NOTE: Lists and maps are unioned, while all other fields are replaced.
+    pub fn merge(&mut self, other: PartialConfig) {
+        macro_rules! overlay {
+            ($($field:ident),*) => {
+                $(
+                    if let Some(v) = other.$field {
+                        self.$field = v;
+                    }
+                )*
+            };
+        }
+
+        macro_rules! overlay_optional {
+            ($($field:ident),*) => {
+                $(
+                    if other.$field.is_some() {
+                        self.$field = other.$field;
+                    }
+                )*
+            };
+        }
+
+        overlay!(
+            check_blank_lines,
+            closed_change_types,
+            commit_message,
+            changelog_path,
+            escape_pr_hash,
+            forge,
+            lenient_releases,
+            max_github_attempts,
+            spelling_boundary,
+            target_repo,
+            use_categories,
+            warn_empty_unreleased
+        );
+
+        overlay_optional!(
+            date_format,
+            extends,
+            github_host,
+            max_description_length,
+            mirror_repo,
+            legacy_version,
+            unreleased_placeholder
+        );
+
+        for list in [
+            (&mut self.categories, other.categories),
+            (&mut self.commit_trailers, other.commit_trailers),
+            (&mut self.forbidden_terms, other.forbidden_terms),
+            (&mut self.imperative_blocklist, other.imperative_blocklist),
+        ] {
+            let (target, values) = list;
+            values.unwrap_or_default().into_iter().for_each(|v| {
+                if !target.contains(&v) {
+                    target.push(v)
+                }
+            });
+        }
+
+        for map in [
+            (&mut self.change_types, other.change_types),
+            (&mut self.expected_spellings, other.expected_spellings),
+            (&mut self.label_change_type_map, other.label_change_type_map),
+        ] {
+            let (target, values) = map;
+            target.extend(values.unwrap_or_default());
+        }
+    }
+
+    /// Applies the overrides of the configuration, that are set
+    /// as environment variables (`CLU_TARGET_REPO` and `CLU_CHANGELOG_PATH`).
+    ///
+    /// NOTE: The environment is passed in as a map, so that it can be
+    /// controlled independently of the process environment.
+    pub fn apply_env_overrides(
+        &mut self,
+        env: &BTreeMap<String, String>,
+    ) -> Result<(), ConfigError> {
+        if let Some(target_repo) = env.get("CLU_TARGET_REPO") {
+            set_target_repo(self, target_repo.to_string())?;
+        }
+
+        if let Some(changelog_path) = env.get("CLU_CHANGELOG_PATH") {
+            if changelog_path.is_empty() {
+                return Err(ConfigError::EmptyEnvOverride(
+                    "CLU_CHANGELOG_PATH".to_string(),
+                ));
+            }
+            self.changelog_path = changelog_path.to_string();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the configured date format or the ISO date format as the default.
+    pub fn get_date_format(&self) -> &str {
+        self.date_format.as_deref().unwrap_or("%Y-%m-%d")
+    }
+
+    /// Returns the configured date format after checking that it can be used
+    /// to format dates.
+    pub fn get_valid_date_format(&self) -> Result<&str, ConfigError> {
+        let date_format = self.get_date_format();
+        check_date_format(date_format)?;
+
+        Ok(date_format)
+    }
+
+    /// Returns the configured host or the default host of the configured forge.
+    pub fn get_forge_host(&self) -> &str {
+        self.github_host
+            .as_deref()
+            .unwrap_or(self.forge.default_host())
     }
 
     pub fn has_legacy_version(&self) -> bool {
@@ -48,6 +368,26 @@ impl Config {
     }
 }
 
+impl PartialConfig {
+    /// Exports the fields, that are set in the partial configuration, to the given path.
+    ///
+    /// NOTE: The configuration is written as TOML if the path has a `.toml` extension
+    /// and as JSON otherwise.
+    pub fn export(&self, path: &Path) -> Result<(), ConfigError> {
+        let mut value = serde_json::to_value(self)?;
+        if let Some(fields) = value.as_object_mut() {
+            fields.retain(|_, v| !v.is_null());
+        }
+
+        let contents = match is_toml(path) {
+            true => toml::to_string_pretty(&value)?,
+            false => serde_json::to_string_pretty(&value)?,
+        };
+
+        Ok(fs::write(path, contents)?)
+    }
+}
+
 impl fmt::Display for Config {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", serde_json::to_string_pretty(&self).unwrap())
@@ -67,11 +407,30 @@ impl Default for Config {
         Config {
             categories: Vec::default(),
             change_types: default_change_types,
+            check_blank_lines: false,
+            closed_change_types: false,
             commit_message,
+            commit_trailers: Vec::default(),
             changelog_path,
+            date_format: None,
+            escape_pr_hash: false,
             expected_spellings: BTreeMap::default(),
+            extends: None,
+            forbidden_terms: Vec::default(),
+            forge: ForgeKind::default(),
+            github_host: None,
+            imperative_blocklist: Vec::default(),
+            label_change_type_map: BTreeMap::default(),
             legacy_version: None,
+            lenient_releases: false,
+            max_description_length: None,
+            max_github_attempts: default_max_github_attempts(),
+            mirror_repo: None,
+            spelling_boundary: String::default(),
             target_repo: String::default(),
+            unreleased_placeholder: None,
+            use_categories: true,
+            warn_empty_unreleased: false,
         }
     }
 }
@@ -82,31 +441,134 @@ pub fn unpack_config(contents: &str) -> Result<Config, ConfigError> {
     Ok(config)
 }
 
+// Unpacks the configuration from a given raw TOML string.
+pub fn unpack_toml_config(contents: &str) -> Result<Config, ConfigError> {
+    let config: Config = toml::from_str(contents)?;
+    Ok(config)
+}
+
 // Tries to open the configuration file in the expected location
 // and load the configuration.
 pub fn load() -> Result<Config, ConfigError> {
-    unpack_config(fs::read_to_string(".clconfig.json")?.as_str())
+    load_from_path(&get_config_path(Path::new("./")))
+}
+
+// Loads the configuration from the given path and merges it onto
+// the parent configurations, that it extends. Afterwards, the overrides
+// from the environment are applied.
+pub fn load_from_path(path: &Path) -> Result<Config, ConfigError> {
+    let mut visited: Vec<PathBuf> = Vec::new();
+    let mut config = load_with_parents(path, &mut visited)?;
+    config.apply_env_overrides(&std::env::vars().collect())?;
+
+    Ok(config)
+}
+
+fn load_with_parents(path: &Path, visited: &mut Vec<PathBuf>) -> Result<Config, ConfigError> {
+    let canonical_path = path.canonicalize()?;
+    if visited.contains(&canonical_path) {
+        return Err(ConfigError::CyclicExtends(
+            path.to_string_lossy().to_string(),
+        ));
+    }
+    visited.push(canonical_path);
+
+    // NOTE: a configuration, that extends a parent, only has to contain the overridden fields
+    let partial = read_partial_from_path(path)?;
+    let Some(parent) = partial.extends.clone() else {
+        return read_from_path(path);
+    };
+
+    let parent_path = path.parent().unwrap_or(Path::new("./")).join(parent);
+    let mut merged = load_with_parents(&parent_path, visited)?;
+    merged.merge(partial);
+    merged.extends = None;
+
+    Ok(merged)
+}
+
+// Reads the configuration from the given path without resolving the
+// parent configurations. The file is parsed as TOML if it has a `.toml`
+// extension and as JSON otherwise.
+pub fn read_from_path(path: &Path) -> Result<Config, ConfigError> {
+    let contents = fs::read_to_string(path)?;
+
+    match is_toml(path) {
+        true => unpack_toml_config(contents.as_str()),
+        false => unpack_config(contents.as_str()),
+    }
+}
+
+// Reads the configuration from the given path, where all fields are optional.
+pub fn read_partial_from_path(path: &Path) -> Result<PartialConfig, ConfigError> {
+    let contents = fs::read_to_string(path)?;
+
+    Ok(match is_toml(path) {
+        true => toml::from_str(contents.as_str())?,
+        false => serde_json::from_str(contents.as_str())?,
+    })
+}
+
+// Returns the path of the configuration file in the given directory.
+//
+// NOTE: The JSON configuration takes precedence if both a JSON and a TOML
+// configuration exist. If none exists, the path of the JSON configuration is returned.
+pub fn get_config_path(dir: &Path) -> PathBuf {
+    let toml_path = dir.join(".clconfig.toml");
+    match !dir.join(".clconfig.json").exists() && toml_path.exists() {
+        true => toml_path,
+        false => dir.join(".clconfig.json"),
+    }
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "toml")
+}
+
+// Adds an expected spelling with the pattern of possible misspellings.
+//
+// NOTE: Spellings, that only differ by case from an existing one, are rejected
+// because the patterns are matched case-insensitively.
+pub fn add_expected_spelling(
+    expected_spellings: &mut BTreeMap<String, String>,
+    key: String,
+    value: String,
+) -> Result<(), ConfigAdjustError> {
+    if let Some(existing) = expected_spellings
+        .keys()
+        .find(|k| k.ne(&&key) && k.eq_ignore_ascii_case(&key))
+    {
+        return Err(ConfigAdjustError::SpellingCollision(
+            key,
+            existing.to_string(),
+        ));
+    }
+
+    add_into_collection(expected_spellings, key, value)
 }
 
 // Adds a category to the list of allowed categories.
-pub fn add_category(config: &mut Config, value: String) -> Result<(), ConfigAdjustError> {
-    if config.categories.contains(&value) {
+pub fn add_category(categories: &mut Vec<String>, value: String) -> Result<(), ConfigAdjustError> {
+    if categories.contains(&value) {
         return Err(ConfigAdjustError::CategoryAlreadyFound);
     }
 
-    config.categories.push(value);
-    config.categories.sort_unstable();
+    categories.push(value);
+    categories.sort_unstable();
 
     Ok(())
 }
 
 // Removes a category from the list of allowed categories.
-pub fn remove_category(config: &mut Config, value: String) -> Result<(), ConfigAdjustError> {
-    let index = match config.categories.iter().position(|x| x == &value) {
+pub fn remove_category(
+    categories: &mut Vec<String>,
+    value: String,
+) -> Result<(), ConfigAdjustError> {
+    let index = match categories.iter().position(|x| x == &value) {
         Some(i) => i,
         None => return Err(ConfigAdjustError::NotFound),
     };
-    config.categories.remove(index);
+    categories.remove(index);
 
     Ok(())
 }
@@ -136,22 +598,165 @@ pub fn remove_from_collection(
     }
 }
 
-// Checks if the given value is a valid GitHub URL and sets the target
+// Renames a key in the given collection while keeping the associated value.
+pub fn rename_in_collection(
+    hm: &mut BTreeMap<String, String>,
+    old: String,
+    new: String,
+) -> Result<(), ConfigAdjustError> {
+    if hm.contains_key(&new) {
+        return Err(ConfigAdjustError::KeyAlreadyFound);
+    }
+
+    match hm.remove(&old) {
+        Some(v) => {
+            hm.insert(new, v);
+            Ok(())
+        }
+        None => Err(ConfigAdjustError::NotFound),
+    }
+}
+
+// Checks if the given value is a valid URL on the configured forge host and sets the target
 // repository field if it is the case.
 pub fn set_target_repo(config: &mut Config, value: String) -> Result<(), ConfigAdjustError> {
-    match Url::parse(value.as_str())?.domain() {
-        Some(d) => {
-            if d != "github.com" {
-                return Err(ConfigAdjustError::NoGitHubRepository);
-            }
-        }
-        None => return Err(ConfigAdjustError::NoGitHubRepository),
+    config.target_repo = get_valid_target_repo(config, value)?;
+    Ok(())
+}
+
+// Checks if the given value is a valid URL on the configured forge host
+// and returns the normalized repository URL.
+pub fn get_valid_target_repo(config: &Config, value: String) -> Result<String, ConfigAdjustError> {
+    let value = normalize_repo_url(value.as_str());
+    let host = config.get_forge_host();
+    if Url::parse(value.as_str())?.domain() != Some(host) {
+        return Err(ConfigAdjustError::UnexpectedHost(host.to_string()));
+    }
+
+    Ok(value)
+}
+
+/// Returns the value of the given configuration field as a raw string.
+///
+/// NOTE: Lists are printed with one item per line and maps with
+/// one `key: value` pair per line.
+pub fn get_value(config: &Config, key: &str) -> Result<String, ConfigError> {
+    let serialized = serde_json::to_value(config)?;
+    let value = serialized
+        .get(key)
+        .ok_or(ConfigError::UnknownKey(key.to_string()))?;
+
+    Ok(format_value(value))
+}
+
+fn format_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::default(),
+        serde_json::Value::String(s) => s.to_string(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(format_value)
+            .collect::<Vec<String>>()
+            .join("\n"),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| format!("{k}: {}", format_value(v)))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        other => other.to_string(),
+    }
+}
+
+/// Checks that the given date format is a valid strftime string, that only
+/// contains specifiers, which can be applied to dates.
+///
+/// NOTE: Invalid formats would otherwise cause a panic when formatting a date.
+pub fn check_date_format(date_format: &str) -> Result<(), ConfigError> {
+    let items: Vec<Item> = StrftimeItems::new(date_format).collect();
+    if items.iter().any(|i| matches!(i, Item::Error)) {
+        return Err(ConfigError::InvalidDateFormat(date_format.to_string()));
+    }
+
+    // NOTE: time specifiers (e.g. `%H`) are valid items but cannot be applied to dates
+    let mut formatted = String::new();
+    let date = NaiveDate::default().format_with_items(items.into_iter());
+    if write!(formatted, "{date}").is_err() {
+        return Err(ConfigError::InvalidDateFormat(date_format.to_string()));
     }
 
-    config.target_repo = value;
     Ok(())
 }
 
+/// Normalizes the given repository URL by adding a missing `https://` scheme
+/// and removing a trailing slash or `.git` suffix.
+pub fn normalize_repo_url(value: &str) -> String {
+    let mut normalized = value.trim().trim_end_matches('/').to_string();
+
+    if let Some(stripped) = normalized.strip_suffix(".git") {
+        normalized = stripped.to_string();
+    }
+
+    if !normalized.contains("://") {
+        normalized = format!("https://{normalized}");
+    }
+
+    normalized
+}
+
+/// Checks the configuration for inconsistencies and returns the found problems.
+pub fn validate(config: &Config) -> Vec<String> {
+    let mut problems: Vec<String> = Vec::new();
+
+    if config.target_repo.is_empty() {
+        problems.push("target repository is empty".to_string());
+    }
+
+    let mut seen_shorts: Vec<&String> = Vec::new();
+    for (change_type, short) in config.change_types.iter() {
+        if seen_shorts.contains(&short) {
+            problems.push(format!(
+                "duplicate change type short '{short}' for change type '{change_type}'"
+            ));
+        } else {
+            seen_shorts.push(short);
+        }
+    }
+
+    for (spelling, pattern) in config.expected_spellings.iter() {
+        if let Err(e) = compile_regex(pattern) {
+            problems.push(format!(
+                "invalid pattern for spelling '{spelling}': {pattern}; {e}"
+            ));
+        }
+    }
+
+    let spellings: Vec<&String> = config.expected_spellings.keys().collect();
+    for (i, spelling) in spellings.iter().enumerate() {
+        if let Some(other) = spellings[i + 1..]
+            .iter()
+            .find(|s| s.eq_ignore_ascii_case(spelling))
+        {
+            problems.push(format!(
+                "spelling '{other}' differs only by case from '{spelling}'"
+            ));
+        }
+    }
+
+    if let Some(legacy_version) = &config.legacy_version {
+        if let Err(e) = version::parse(legacy_version) {
+            problems.push(format!("invalid legacy version '{legacy_version}': {e}"));
+        }
+    }
+
+    if let Some(date_format) = &config.date_format {
+        if let Err(e) = check_date_format(date_format) {
+            problems.push(e.to_string());
+        }
+    }
+
+    problems
+}
+
 #[cfg(test)]
 mod config_tests {
     use super::*;
@@ -163,19 +768,19 @@ mod config_tests {
         println!("{:?}", config);
 
         assert!(
-            config.expected_spellings.len() > 0,
+            !config.expected_spellings.is_empty(),
             "expected non-zero length of example configuration spellings"
         );
         assert_eq!(config.expected_spellings.get("API").unwrap(), "api");
 
         assert!(
-            config.change_types.len() > 0,
+            !config.change_types.is_empty(),
             "expected non-zero length of change types in example config"
         );
         assert_eq!(config.change_types.get("Bug Fixes").unwrap(), "fix");
 
         assert!(
-            config.categories.len() > 0,
+            !config.categories.is_empty(),
             "expected non-zero length of categories in example config",
         );
         assert!(
@@ -216,7 +821,7 @@ mod config_adjustment_tests {
         let mut config = load_example_config();
         assert_eq!(config.categories.len(), 2);
         assert!(!config.categories.contains(&"new".to_string()));
-        assert!(add_category(&mut config, "new".into()).is_ok());
+        assert!(add_category(&mut config.categories, "new".into()).is_ok());
         assert_eq!(config.categories.len(), 3);
         assert!(config.categories.contains(&"new".to_string()));
     }
@@ -226,7 +831,7 @@ mod config_adjustment_tests {
         let mut config = load_example_config();
         assert_eq!(config.categories.len(), 2);
         assert_eq!(
-            add_category(&mut config, "test".to_string()).unwrap_err(),
+            add_category(&mut config.categories, "test".to_string()).unwrap_err(),
             ConfigAdjustError::CategoryAlreadyFound
         );
         assert_eq!(config.categories.len(), 2);
@@ -236,7 +841,7 @@ mod config_adjustment_tests {
     fn test_remove_category() {
         let mut config = load_example_config();
         assert_eq!(config.categories.len(), 2);
-        assert!(remove_category(&mut config, "test".to_string()).is_ok());
+        assert!(remove_category(&mut config.categories, "test".to_string()).is_ok());
         assert_eq!(config.categories.len(), 1);
     }
 
@@ -245,7 +850,7 @@ mod config_adjustment_tests {
         let mut config = load_example_config();
         assert_eq!(config.categories.len(), 2);
         assert_eq!(
-            remove_category(&mut config, "not-found".to_string()).unwrap_err(),
+            remove_category(&mut config.categories, "not-found".to_string()).unwrap_err(),
             ConfigAdjustError::NotFound
         );
         assert_eq!(config.categories.len(), 2);
@@ -304,13 +909,56 @@ mod config_adjustment_tests {
         assert_eq!(config.change_types.keys().len(), 3);
     }
 
+    #[test]
+    fn test_rename_in_collection() {
+        let mut config = load_example_config();
+        let pattern = config.expected_spellings.get("Web-SDK").unwrap().clone();
+        assert!(rename_in_collection(
+            &mut config.expected_spellings,
+            "Web-SDK".to_string(),
+            "WebSDK".to_string()
+        )
+        .is_ok());
+        assert!(!config.expected_spellings.contains_key("Web-SDK"));
+        assert_eq!(config.expected_spellings.get("WebSDK"), Some(&pattern));
+    }
+
+    #[test]
+    fn test_rename_in_collection_already_present() {
+        let mut config = load_example_config();
+        assert_eq!(
+            rename_in_collection(
+                &mut config.expected_spellings,
+                "API".to_string(),
+                "CLI".to_string()
+            )
+            .unwrap_err(),
+            ConfigAdjustError::KeyAlreadyFound
+        );
+        assert_eq!(config.expected_spellings.get("API").unwrap(), "api");
+    }
+
+    #[test]
+    fn test_rename_in_collection_not_found() {
+        let mut config = load_example_config();
+        assert_eq!(
+            rename_in_collection(
+                &mut config.expected_spellings,
+                "not found".to_string(),
+                "new".to_string()
+            )
+            .unwrap_err(),
+            ConfigAdjustError::NotFound
+        );
+    }
+
     #[test]
     fn test_set_target_repo_fail() {
         let mut config = load_example_config();
         let new_target = "https://other-link.com/MalteHerrmann/other-repo";
         assert_eq!(
             set_target_repo(&mut config, new_target.to_string()).unwrap_err(),
-            ConfigAdjustError::NoGitHubRepository
+            ConfigAdjustError::UnexpectedHost("github.com".to_string())
         );
         assert_ne!(config.target_repo, new_target);
     }
@@ -323,4 +971,350 @@ mod config_adjustment_tests {
         assert!(set_target_repo(&mut config, new_target.to_string()).is_ok());
         assert_eq!(config.target_repo, new_target);
     }
+
+    #[test]
+    fn test_forge_kind_serialization() {
+        let config = unpack_config(include_str!("testdata/example_config.json"))
+            .expect("failed to parse config");
+        assert_eq!(config.forge, ForgeKind::GitHub);
+
+        let mut contents: serde_json::Value =
+            serde_json::from_str(include_str!("testdata/example_config.json")).unwrap();
+        contents["forge"] = "gitlab".into();
+        let config = unpack_config(contents.to_string().as_str()).expect("failed to parse config");
+        assert_eq!(config.forge, ForgeKind::GitLab);
+    }
+
+    #[test]
+    fn test_validate_pass() {
+        let config = load_example_config();
+        assert!(validate(&config).is_empty());
+    }
+
+    #[test]
+    fn test_validate_empty_target_repo() {
+        let mut config = load_example_config();
+        config.target_repo = String::default();
+        assert_eq!(validate(&config), vec!["target repository is empty"]);
+    }
+
+    #[test]
+    fn test_validate_duplicate_change_type_short() {
+        let mut config = load_example_config();
+        config.change_types.insert("Z Fixes".into(), "fix".into());
+        assert_eq!(
+            validate(&config),
+            vec!["duplicate change type short 'fix' for change type 'Z Fixes'"]
+        );
+    }
+
+    #[test]
+    fn test_validate_invalid_spelling_pattern() {
+        let mut config = load_example_config();
+        config
+            .expected_spellings
+            .insert("API".into(), "api(".into());
+        let problems = validate(&config);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("invalid pattern for spelling 'API': api("));
+    }
+
+    #[test]
+    fn test_validate_spelling_collision() {
+        let mut config = load_example_config();
+        config.expected_spellings.insert("Api".into(), "api".into());
+        assert_eq!(
+            validate(&config),
+            vec!["spelling 'Api' differs only by case from 'API'"]
+        );
+    }
+
+    #[test]
+    fn test_add_expected_spelling_collision() {
+        let mut config = load_example_config();
+        assert_eq!(
+            add_expected_spelling(&mut config.expected_spellings, "Api".into(), "api".into())
+                .unwrap_err(),
+            ConfigAdjustError::SpellingCollision("Api".into(), "API".into())
+        );
+        assert!(!config.expected_spellings.contains_key("Api"));
+
+        assert_eq!(
+            add_expected_spelling(&mut config.expected_spellings, "API".into(), "api".into())
+                .unwrap_err(),
+            ConfigAdjustError::KeyAlreadyFound
+        );
+        assert!(
+            add_expected_spelling(&mut config.expected_spellings, "EVM".into(), "evm".into())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_invalid_legacy_version() {
+        let mut config = load_example_config();
+        config.legacy_version = Some("1.0".into());
+        let problems = validate(&config);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("invalid legacy version '1.0'"));
+    }
+
+    #[test]
+    fn test_validate_invalid_date_format() {
+        let mut config = load_example_config();
+        config.date_format = Some("%d.%m.%Y".into());
+        assert!(validate(&config).is_empty());
+
+        config.date_format = Some("%d.%m.%Y %H:%M".into());
+        assert_eq!(
+            validate(&config),
+            vec!["invalid date format: '%d.%m.%Y %H:%M'"]
+        );
+    }
+
+    #[test]
+    fn test_get_value_scalar() {
+        let config = load_example_config();
+        assert_eq!(
+            get_value(&config, "target_repo").expect("failed to get value"),
+            "https://github.com/MalteHerrmann/changelog-utils"
+        );
+        assert_eq!(
+            get_value(&config, "use_categories").expect("failed to get value"),
+            "true"
+        );
+        assert_eq!(
+            get_value(&config, "mirror_repo").expect("failed to get value"),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_get_value_collection() {
+        let config = load_example_config();
+        assert_eq!(
+            get_value(&config, "categories").expect("failed to get value"),
+            "cli\ntest"
+        );
+        assert_eq!(
+            get_value(&config, "change_types").expect("failed to get value"),
+            "Bug Fixes: fix\nFeatures: feat\nImprovements: imp"
+        );
+    }
+
+    #[test]
+    fn test_get_value_unknown_key() {
+        let config = load_example_config();
+        assert!(matches!(
+            get_value(&config, "unknown"),
+            Err(ConfigError::UnknownKey(k)) if k == "unknown"
+        ));
+    }
+
+    #[test]
+    fn test_set_target_repo_normalized() {
+        for new_target in [
+            "github.com/MalteHerrmann/other-repo",
+            "https://github.com/MalteHerrmann/other-repo/",
+            "https://github.com/MalteHerrmann/other-repo.git",
+        ] {
+            let mut config = load_example_config();
+            assert!(set_target_repo(&mut config, new_target.to_string()).is_ok());
+            assert_eq!(
+                config.target_repo,
+                "https://github.com/MalteHerrmann/other-repo"
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_target_repo_enterprise_pass() {
+        let mut config = load_example_config();
+        config.github_host = Some("github.mycorp.com".into());
+        let new_target = "https://github.mycorp.com/MalteHerrmann/other-repo";
+        assert!(set_target_repo(&mut config, new_target.to_string()).is_ok());
+        assert_eq!(config.target_repo, new_target);
+    }
+
+    #[test]
+    fn test_set_target_repo_gitlab() {
+        let mut config = load_example_config();
+        config.forge = ForgeKind::GitLab;
+        let new_target = "https://gitlab.com/MalteHerrmann/other-repo";
+        assert!(set_target_repo(&mut config, new_target.to_string()).is_ok());
+        assert_eq!(config.target_repo, new_target);
+
+        assert_eq!(
+            set_target_repo(
+                &mut config,
+                "https://github.com/MalteHerrmann/other-repo".to_string()
+            )
+            .unwrap_err(),
+            ConfigAdjustError::UnexpectedHost("gitlab.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_set_target_repo_enterprise_fail() {
+        let mut config = load_example_config();
+        config.github_host = Some("github.mycorp.com".into());
+        for new_target in [
+            "https://github.com/MalteHerrmann/other-repo",
+            "https://random-host.com/MalteHerrmann/other-repo",
+        ] {
+            assert_eq!(
+                set_target_repo(&mut config, new_target.to_string()).unwrap_err(),
+                ConfigAdjustError::UnexpectedHost("github.mycorp.com".to_string())
+            );
+            assert_ne!(config.target_repo, new_target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod merge_tests {
+    use super::*;
+
+    fn load_example_config() -> Config {
+        unpack_config(include_str!("testdata/example_config.json"))
+            .expect("failed to load example config")
+    }
+
+    #[test]
+    fn test_merge_spellings() {
+        let mut config = load_example_config();
+        let other = PartialConfig {
+            expected_spellings: Some(BTreeMap::from([
+                ("API".into(), "a\\.p\\.i".into()),
+                ("EVM".into(), "evm".into()),
+            ])),
+            ..PartialConfig::default()
+        };
+
+        config.merge(other);
+        assert_eq!(config.expected_spellings.len(), 4);
+        assert_eq!(config.expected_spellings["API"], "a\\.p\\.i");
+        assert_eq!(config.expected_spellings["CLI"], "cli");
+        assert_eq!(config.expected_spellings["EVM"], "evm");
+    }
+
+    #[test]
+    fn test_merge_categories() {
+        let mut config = load_example_config();
+        let other = PartialConfig {
+            categories: Some(vec!["test".into(), "evm".into()]),
+            ..PartialConfig::default()
+        };
+
+        config.merge(other);
+        assert_eq!(config.categories, vec!["cli", "test", "evm"]);
+    }
+
+    #[test]
+    fn test_merge_scalar_overrides() {
+        let mut config = load_example_config();
+        let other = PartialConfig {
+            target_repo: Some("https://github.com/MalteHerrmann/other-repo".into()),
+            use_categories: Some(false),
+            max_description_length: Some(80),
+            ..PartialConfig::default()
+        };
+
+        config.merge(other);
+        assert_eq!(
+            config.target_repo,
+            "https://github.com/MalteHerrmann/other-repo"
+        );
+        assert!(!config.use_categories);
+        assert_eq!(config.max_description_length, Some(80));
+        // unset fields do not override the existing configuration
+        assert_eq!(config.legacy_version, Some("v2.1.0".into()));
+        assert_eq!(config.commit_message, "add changelog entry");
+        assert_eq!(config.change_types.len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod env_override_tests {
+    use super::*;
+
+    fn load_example_config() -> Config {
+        unpack_config(include_str!("testdata/example_config.json"))
+            .expect("failed to load example config")
+    }
+
+    fn get_env(vars: &[(&str, &str)]) -> BTreeMap<String, String> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_no_env_overrides() {
+        let mut config = load_example_config();
+        config
+            .apply_env_overrides(&get_env(&[]))
+            .expect("failed to apply overrides");
+        assert_eq!(
+            config.target_repo,
+            "https://github.com/MalteHerrmann/changelog-utils"
+        );
+        assert_eq!(config.changelog_path, "CHANGELOG.md");
+    }
+
+    #[test]
+    fn test_apply_env_overrides() {
+        let mut config = load_example_config();
+        config
+            .apply_env_overrides(&get_env(&[
+                ("CLU_TARGET_REPO", "github.com/MalteHerrmann/other-repo"),
+                ("CLU_CHANGELOG_PATH", "docs/CHANGELOG.md"),
+            ]))
+            .expect("failed to apply overrides");
+        assert_eq!(
+            config.target_repo,
+            "https://github.com/MalteHerrmann/other-repo"
+        );
+        assert_eq!(config.changelog_path, "docs/CHANGELOG.md");
+    }
+
+    #[test]
+    fn test_apply_gitlab_target_repo_override() {
+        let mut config = load_example_config();
+        config.forge = ForgeKind::GitLab;
+        config
+            .apply_env_overrides(&get_env(&[(
+                "CLU_TARGET_REPO",
+                "https://gitlab.com/MalteHerrmann/other-repo",
+            )]))
+            .expect("failed to apply overrides");
+        assert_eq!(
+            config.target_repo,
+            "https://gitlab.com/MalteHerrmann/other-repo"
+        );
+    }
+
+    #[test]
+    fn test_fail_invalid_target_repo_override() {
+        let mut config = load_example_config();
+        assert!(matches!(
+            config.apply_env_overrides(&get_env(&[(
+                "CLU_TARGET_REPO",
+                "https://random-host.com/MalteHerrmann/other-repo"
+            )])),
+            Err(ConfigError::InvalidEnvOverride(
+                ConfigAdjustError::UnexpectedHost(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_fail_empty_changelog_path_override() {
+        let mut config = load_example_config();
+        assert!(matches!(
+            config.apply_env_overrides(&get_env(&[("CLU_CHANGELOG_PATH", "")])),
+            Err(ConfigError::EmptyEnvOverride(v)) if v == "CLU_CHANGELOG_PATH"
+        ));
+        assert_eq!(config.changelog_path, "CHANGELOG.md");
+    }
 }
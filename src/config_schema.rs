@@ -0,0 +1,205 @@
+use serde_json::{json, Value};
+
+/// Returns the JSON Schema describing the configuration file,
+/// which can be used for validation and autocompletion in editors.
+pub fn get_schema() -> Value {
+    let string_list = json!({ "type": "array", "items": { "type": "string" } });
+    let string_map = json!({ "type": "object", "additionalProperties": { "type": "string" } });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "clu configuration",
+        "description": "The configuration of the changelog utilities (.clconfig.json).",
+        "type": "object",
+        // NOTE: a configuration, that extends a parent, only has to contain the overridden fields
+        "if": { "not": { "required": ["extends"] } },
+        "then": {
+            "required": [
+                "categories",
+                "change_types",
+                "changelog_path",
+                "commit_message",
+                "expected_spellings",
+                "target_repo"
+            ]
+        },
+        "properties": {
+            "categories": with_description(
+                &string_list,
+                "The list of categories, that can be used for the entries."
+            ),
+            "change_types": with_description(
+                &string_map,
+                "The map of allowed change types with the full spelling as the key and the abbreviation used in PR titles as the value."
+            ),
+            "changelog_path": {
+                "type": "string",
+                "description": "The relative path of the changelog file."
+            },
+            "check_blank_lines": {
+                "type": "boolean",
+                "default": false,
+                "description": "Whether to report blank lines, that deviate from the layout of the fixed changelog."
+            },
+            "closed_change_types": {
+                "type": "boolean",
+                "default": false,
+                "description": "Whether unknown change types cause the linter to fail."
+            },
+            "commit_message": {
+                "type": "string",
+                "description": "The default commit message used when committing a new changelog entry."
+            },
+            "commit_trailers": with_description(
+                &string_list,
+                "The list of trailers added to the commit message when committing a new changelog entry (e.g. 'Signed-off-by: Jane Doe <jane@example.com>')."
+            ),
+            "date_format": {
+                "type": ["string", "null"],
+                "default": null,
+                "description": "The date format of the releases (e.g. '%d.%m.%Y'); ISO dates are expected if not set."
+            },
+            "escape_pr_hash": {
+                "type": "boolean",
+                "default": false,
+                "description": "Whether the '#' in PR links is expected to be escaped."
+            },
+            "expected_spellings": with_description(
+                &string_map,
+                "The map of expected spellings with the correct spelling as the key and a RegEx pattern of possible misspellings as the value."
+            ),
+            "extends": {
+                "type": ["string", "null"],
+                "default": null,
+                "description": "The path of a parent configuration, that this configuration is merged onto."
+            },
+            "forbidden_terms": with_description(
+                &string_list,
+                "The list of words or phrases, that must not occur in entry descriptions."
+            ),
+            "forge": {
+                "type": "string",
+                "enum": ["github", "gitlab"],
+                "default": "github",
+                "description": "The kind of forge, that hosts the target repository."
+            },
+            "github_host": {
+                "type": ["string", "null"],
+                "default": null,
+                "description": "The host of the forge instance; defaults to 'github.com' or 'gitlab.com' depending on the forge if not set."
+            },
+            "imperative_blocklist": with_description(
+                &string_list,
+                "The list of words, that are not allowed as the first word of an entry description."
+            ),
+            "label_change_type_map": with_description(
+                &string_map,
+                "The map of PR labels to change type abbreviations."
+            ),
+            "legacy_version": {
+                "type": ["string", "null"],
+                "description": "The version up to which releases don't need to adhere to the linter standards."
+            },
+            "lenient_releases": {
+                "type": "boolean",
+                "default": false,
+                "description": "Whether release headers without the version in brackets are accepted."
+            },
+            "max_description_length": {
+                "type": ["integer", "null"],
+                "minimum": 0,
+                "default": null,
+                "description": "The maximum number of characters for entry descriptions."
+            },
+            "max_github_attempts": {
+                "type": "integer",
+                "minimum": 0,
+                "default": 3,
+                "description": "The maximum number of attempts for rate-limited GitHub API calls."
+            },
+            "mirror_repo": {
+                "type": ["string", "null"],
+                "default": null,
+                "description": "The base URL of a mirror repository, that entries are expected to link to."
+            },
+            "spelling_boundary": {
+                "type": "string",
+                "default": "",
+                "description": "Additional characters, that are accepted after a matched spelling."
+            },
+            "target_repo": {
+                "type": "string",
+                "description": "The target repository, that represents the base URL enforced in PR links."
+            },
+            "unreleased_placeholder": {
+                "type": ["string", "null"],
+                "default": null,
+                "description": "The placeholder line inserted below an empty Unreleased section."
+            },
+            "use_categories": {
+                "type": "boolean",
+                "default": true,
+                "description": "Whether entries are expected to contain a category."
+            },
+            "warn_empty_unreleased": {
+                "type": "boolean",
+                "default": false,
+                "description": "Whether to report an Unreleased section without any entries."
+            }
+        }
+    })
+}
+
+/// Returns a copy of the given schema with the added description.
+fn with_description(schema: &Value, description: &str) -> Value {
+    let mut schema = schema.clone();
+    schema["description"] = description.into();
+    schema
+}
+
+#[cfg(test)]
+mod config_schema_tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_schema_is_valid_json() {
+        let exported = serde_json::to_string_pretty(&get_schema()).expect("failed to export");
+        let schema: Value = serde_json::from_str(&exported).expect("failed to parse schema");
+
+        for key in ["categories", "change_types", "forge"] {
+            assert!(
+                schema["properties"].get(key).is_some(),
+                "missing key: {key}"
+            );
+        }
+        assert_eq!(
+            schema["properties"]["forge"]["enum"],
+            json!(["github", "gitlab"])
+        );
+    }
+
+    #[test]
+    fn test_schema_requires_fields_only_without_extends() {
+        let schema = get_schema();
+        assert!(schema.get("required").is_none());
+        assert_eq!(schema["if"], json!({ "not": { "required": ["extends"] } }));
+        assert!(schema["then"]["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("target_repo")));
+    }
+
+    #[test]
+    fn test_schema_contains_all_fields() {
+        let config = serde_json::to_value(Config::default()).expect("failed to serialize config");
+        let schema = get_schema();
+
+        for key in config.as_object().unwrap().keys() {
+            assert!(
+                schema["properties"].get(key).is_some(),
+                "missing key: {key}"
+            );
+        }
+    }
+}
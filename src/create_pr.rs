@@ -1,12 +1,20 @@
-use crate::{config, errors::CreateError, github, inputs};
+use crate::{
+    add, changelog, cli::CreatePRArgs, config, entry, errors::CreateError, github, inputs,
+};
+use octocrab::pulls::{CreatePullRequestBuilder, PullRequestHandler};
 
 /// Runs the main logic to open a new PR for the current branch.
-pub async fn run() -> Result<(), CreateError> {
+pub async fn run(args: CreatePRArgs) -> Result<(), CreateError> {
+    if github::is_offline() {
+        return Err(CreateError::Offline);
+    }
+
     let config = config::load()?;
     let git_info = github::get_git_info(&config)?;
-    let client = github::get_authenticated_github_client()?;
+    // NOTE: the token is only required to create the pull request itself
+    let client = github::get_github_client();
 
-    if let Ok(pr_info) = github::get_open_pr(git_info.clone()).await {
+    if let Ok(pr_info) = github::get_open_pr(&config, git_info.clone()).await {
         return Err(CreateError::ExistingPR(pr_info.number));
     }
 
@@ -22,24 +30,31 @@ pub async fn run() -> Result<(), CreateError> {
     };
 
     let change_type = inputs::get_change_type(&config, 0)?;
-    let cat = inputs::get_category(&config, 0)?;
+    let cat = match config.use_categories {
+        true => inputs::get_category(&config, 0)?,
+        false => String::new(),
+    };
     let desc = inputs::get_description("")?;
     let pr_body = inputs::get_pr_description()?;
+    let draft = args.draft || inputs::get_permission_to_create_draft()?;
 
     let branches = client
         .repos(&git_info.owner, &git_info.repo)
         .list_branches()
         .send()
         .await?;
-    let target = inputs::get_target_branch(branches)?;
+    let default_branch = github::get_default_branch().unwrap_or("main".to_string());
+    let target = inputs::get_target_branch(branches, default_branch.as_str())?;
 
     let ct = config.change_types.get(&change_type).unwrap();
-    let title = format!("{ct}({cat}): {desc}");
+    let title = match cat.is_empty() {
+        true => format!("{ct}: {desc}"),
+        false => format!("{ct}({cat}): {desc}"),
+    };
 
-    let created_pr = client
-        .pulls(&git_info.owner, &git_info.repo)
-        .create(title, git_info.branch, target)
-        .body(pr_body)
+    let authenticated_client = github::get_authenticated_github_client()?;
+    let handler = authenticated_client.pulls(&git_info.owner, &git_info.repo);
+    let created_pr = build_pr_request(&handler, title, git_info.branch, target, pr_body, draft)
         .send()
         .await?;
 
@@ -50,6 +65,55 @@ pub async fn run() -> Result<(), CreateError> {
             .expect("received no error creating the PR but html_url was None")
     );
 
+    // NOTE: the PR number is used instead of the id, which is GitHub's internal identifier
+    let mut changelog = changelog::load(config.clone())?;
+    add_entry_for_pr(
+        &config,
+        &mut changelog,
+        change_type.as_str(),
+        cat.as_str(),
+        desc.as_str(),
+        created_pr.number,
+    )?;
+    changelog.write(&changelog.path)?;
+
     let cm = inputs::get_commit_message(&config)?;
-    Ok(github::commit_and_push(&config, &cm)?)
+    Ok(github::commit_and_push(
+        &config,
+        &cm,
+        &args.co_authors,
+        args.signoff,
+    )?)
+}
+
+/// Builds the request to create a pull request from the head into the base branch.
+pub fn build_pr_request<'octo, 'b>(
+    handler: &'b PullRequestHandler<'octo>,
+    title: String,
+    head: String,
+    base: String,
+    body: String,
+    draft: bool,
+) -> CreatePullRequestBuilder<'octo, 'b> {
+    handler.create(title, head, base).body(body).draft(draft)
+}
+
+/// Adds the changelog entry for the created pull request with the given number.
+pub fn add_entry_for_pr(
+    config: &config::Config,
+    changelog: &mut changelog::Changelog,
+    change_type: &str,
+    cat: &str,
+    desc: &str,
+    pr_number: u64,
+) -> Result<entry::Entry, CreateError> {
+    Ok(add::add_entry(
+        config,
+        changelog,
+        change_type,
+        cat,
+        desc,
+        pr_number,
+        None,
+    )?)
 }
@@ -0,0 +1,120 @@
+use crate::{
+    changelog, changelog::Changelog, cli::DedupeArgs, config, errors::DedupeError, inputs,
+};
+use clap::ValueEnum;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The entry to keep, if multiple entries reference the same PR.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Keep {
+    First,
+    Last,
+}
+
+/// The position of an entry in the changelog given as the indices
+/// of the release, the change type and the entry.
+type EntryPosition = (usize, usize, usize);
+
+/// Runs the logic to remove the entries, which reference the same PR
+/// as another entry in the changelog.
+pub fn run(args: DedupeArgs) -> Result<(), DedupeError> {
+    let config = config::load()?;
+    let mut changelog = changelog::load(config)?;
+
+    let n_removed = dedupe(&mut changelog, |pr_number, lines| match args.keep {
+        Some(Keep::First) => Ok(0),
+        Some(Keep::Last) => Ok(lines.len() - 1),
+        None => Ok(inputs::get_entry_to_keep(pr_number, lines)?),
+    })?;
+
+    if n_removed == 0 {
+        println!("no duplicate PRs found in changelog");
+        return Ok(());
+    }
+
+    changelog.write(&changelog.path)?;
+    println!("removed {n_removed} duplicate entries");
+
+    Ok(())
+}
+
+/// Removes the duplicate entries from the changelog and returns the number
+/// of removed entries.
+///
+/// For each PR, that is referenced in multiple entries, the given function is called
+/// with the PR number and the lines of these entries in order of appearance.
+/// It returns the index of the entry to keep.
+///
+/// NOTE: Entries, that were already removed for another PR, are not presented again
+/// and entries, that were kept for another PR, are never removed. Change types, that
+/// contain no more entries afterwards, are removed as well.
+pub fn dedupe(
+    changelog: &mut Changelog,
+    mut get_kept: impl FnMut(u64, &[String]) -> Result<usize, DedupeError>,
+) -> Result<usize, DedupeError> {
+    let mut to_remove: BTreeSet<EntryPosition> = BTreeSet::new();
+    let mut to_keep: BTreeSet<EntryPosition> = BTreeSet::new();
+    for (pr_number, positions) in get_duplicates(changelog) {
+        let positions: Vec<EntryPosition> = positions
+            .into_iter()
+            .filter(|p| !to_remove.contains(p))
+            .collect();
+        if positions.len() < 2 {
+            continue;
+        }
+
+        let lines: Vec<String> = positions
+            .iter()
+            .map(|(r, ct, e)| {
+                changelog.releases[*r].change_types[*ct].entries[*e]
+                    .line
+                    .clone()
+            })
+            .collect();
+
+        let kept = get_kept(pr_number, &lines)?;
+        for (i, p) in positions.into_iter().enumerate() {
+            if i == kept {
+                to_keep.insert(p);
+            } else if !to_keep.contains(&p) {
+                to_remove.insert(p);
+            }
+        }
+    }
+
+    // NOTE: the entries are removed in reverse order to keep the remaining positions valid.
+    for (r, ct, e) in to_remove.iter().rev() {
+        changelog.releases[*r].change_types[*ct].entries.remove(*e);
+    }
+
+    changelog
+        .releases
+        .iter_mut()
+        .for_each(|r| r.change_types.retain(|ct| !ct.entries.is_empty()));
+
+    Ok(to_remove.len())
+}
+
+/// Returns the positions of all entries per PR number, that is referenced
+/// in more than one entry.
+///
+/// NOTE: Entries, that reference the same PR multiple times, are only listed once.
+fn get_duplicates(changelog: &Changelog) -> BTreeMap<u64, Vec<EntryPosition>> {
+    let mut positions: BTreeMap<u64, Vec<EntryPosition>> = BTreeMap::new();
+
+    for (r, release) in changelog.releases.iter().enumerate() {
+        for (ct, change_type) in release.change_types.iter().enumerate() {
+            for (e, entry) in change_type.entries.iter().enumerate() {
+                for pr_number in &entry.pr_numbers {
+                    let pr_positions = positions.entry(*pr_number).or_default();
+                    if !pr_positions.contains(&(r, ct, e)) {
+                        pr_positions.push((r, ct, e));
+                    }
+                }
+            }
+        }
+    }
+
+    positions.retain(|_, p| p.len() > 1);
+    positions
+}
@@ -1,20 +1,37 @@
 use crate::{
     config,
     errors::{EntryError, MatchError},
+    problem::Problem,
 };
 use regex::{Error, Regex, RegexBuilder};
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Represents an individual entry in the changelog.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Entry {
     /// The category of the entry
     pub category: String,
+    /// The optional conventional commit type and scope (e.g. `fix(cli)`),
+    /// that the entry was created from.
+    pub commit_type: Option<String>,
+    /// The original line in the changelog.
+    pub line: String,
     /// The fixed line adhering to all standards.
     pub fixed: String,
     /// The PR number for the given change.
-    pub pr_number: u16,
+    ///
+    /// NOTE: If multiple PRs are referenced, this is the first one.
+    pub pr_number: u64,
+    /// All PR numbers referenced in the entry.
+    pub pr_numbers: Vec<u64>,
     /// The list of problems with the given line.
-    pub problems: Vec<String>,
+    pub problems: Vec<Problem>,
+    /// The 1-based columns of the problems, that can be located in the line,
+    /// keyed by the index of the problem in the list of problems.
+    pub problem_columns: BTreeMap<usize, usize>,
+    /// The indented sub-bullets below the entry, which are kept as is.
+    pub sub_items: Vec<String>,
 }
 
 impl Entry {
@@ -22,139 +39,463 @@ impl Entry {
         config: &config::Config,
         category: &str,
         description: &str,
-        pr_number: u16,
+        pr_number: u64,
+        commit_type: Option<&str>,
     ) -> Entry {
-        let link = format!("{}/pull/{}", config.target_repo, pr_number);
-        let fixed = build_fixed(category, link.as_str(), description, pr_number);
+        let link = format!(
+            "{}/{}/{}",
+            config.target_repo,
+            config.forge.pr_path(),
+            pr_number
+        );
+        let mirror_link = config
+            .mirror_repo
+            .as_ref()
+            .map(|m| format!("{}/merge_requests/{}", m, pr_number));
+        let fixed = build_fixed(
+            config.use_categories.then_some(category),
+            &[(pr_number, format!("({link})"))],
+            mirror_link.as_deref(),
+            description,
+            commit_type,
+            config.escape_pr_hash,
+        );
 
         Entry {
             category: category.to_string(),
+            commit_type: commit_type.map(|ct| ct.to_string()),
+            line: fixed.clone(),
             fixed,
             pr_number,
+            pr_numbers: vec![pr_number],
             problems: Vec::new(),
+            problem_columns: BTreeMap::new(),
+            sub_items: Vec::new(),
         }
     }
 }
 
 pub fn parse(config: &config::Config, line: &str) -> Result<Entry, EntryError> {
-    let entry_pattern = Regex::new(concat!(
-        r"^(?P<ws0>\s*)-(?P<ws1>\s*)\((?P<category>[a-zA-Z0-9\-]+)\)",
-        r"(?P<ws2>\s*)\[(?P<bs>\\)?#(?P<pr>\d+)]",
-        r"(?P<ws3>\s*)\((?P<link>[^)]*)\)(?P<ws4>\s*)(?P<desc>.+)$"
+    parse_with_references(config, line, &BTreeMap::new())
+}
+
+/// Parses the given entry line, where reference-style PR links (e.g. `[#1][pr1]`)
+/// are resolved with the given link definitions, that are keyed by their lowercase label.
+///
+/// NOTE: Reference-style links are kept in the fixed entry, so that problems with the
+/// resolved link have to be fixed in the link definition.
+pub fn parse_with_references(
+    config: &config::Config,
+    line: &str,
+    references: &BTreeMap<String, String>,
+) -> Result<Entry, EntryError> {
+    // NOTE: the mirror link is only matched if a mirror repository is configured,
+    // so that the parsing is unchanged otherwise.
+    let mirror_pattern = match config.mirror_repo {
+        Some(_) => r"(?P<mirror>(?P<ws5>\s*)\(\[mirror]\((?P<mirror_link>[^)]*)\)\))?",
+        None => "",
+    };
+    let entry_pattern = Regex::new(
+        format!(
+            "{}{}{}{}",
+            r"^(?P<ws0>\s*)-(?P<ws1>\s*)(\((?P<category>[a-zA-Z0-9\-]+)\)(?P<ws2>\s*))?",
+            r"(?P<links>\[\\?#\d+]\s*(\([^)]*\)|\[[^\]]*])(\s*\[\\?#\d+]\s*(\([^)]*\)|\[[^\]]*]))*)",
+            mirror_pattern,
+            r"(?P<ws4>\s*)(?P<desc>.+)$",
+        )
+        .as_str(),
+    )
+    .expect("invalid regex pattern");
+    let link_pattern = Regex::new(concat!(
+        r"(?P<sep>\s*)\[(?P<bs>\\)?#(?P<pr>\d+)]",
+        r"(?P<ws3>\s*)(\((?P<link>[^)]*)\)|\[(?P<label>[^\]]*)])",
     ))
     .expect("invalid regex pattern");
 
+    let original_line = line.to_string();
+
+    // NOTE: the optional commit type annotation is split off before matching the entry itself
+    let (line, commit_type) = split_commit_type(line);
+
     let matches = match entry_pattern.captures(line) {
         Some(c) => c,
         None => return Err(EntryError::InvalidEntry(line.to_string())),
     };
 
+    // NOTE: the category is optional in the pattern, so that entries without categories
+    // can be parsed if the configuration does not require them.
+    let category = matches.name("category").map(|c| c.as_str());
+    if config.use_categories && category.is_none() {
+        return Err(EntryError::InvalidEntry(line.to_string()));
+    }
+
     // NOTE: calling unwrap here is okay because we checked that the pattern matched above
-    let category = matches.name("category").unwrap().as_str();
     let description = matches.name("desc").unwrap().as_str();
-    let link = matches.name("link").unwrap().as_str();
-    let pr_number = matches.name("pr").unwrap().as_str().parse::<u16>().unwrap();
+    let links: Vec<regex::Captures> = link_pattern
+        .captures_iter(matches.name("links").unwrap().as_str())
+        .collect();
+    let first_link = links.first().expect("at least one link should be matched");
+    let pr_numbers: Vec<u64> = links
+        .iter()
+        .map(|l| l.name("pr").unwrap().as_str().parse::<u64>().unwrap())
+        .collect();
+    // NOTE: the link captures are relative to the start of the links group
+    let links_start = matches.name("links").unwrap().start();
+    let get_column = |offset: usize| line[..offset].chars().count() + 1;
+
     let spaces = [
-        matches.name("ws0").unwrap().as_str(),
-        matches.name("ws1").unwrap().as_str(),
-        matches.name("ws2").unwrap().as_str(),
-        matches.name("ws3").unwrap().as_str(),
-        matches.name("ws4").unwrap().as_str(),
+        whitespace_with_column(matches.name("ws0").unwrap(), 0, get_column),
+        whitespace_with_column(matches.name("ws1").unwrap(), 0, get_column),
+        // NOTE: without a category there is no whitespace to check between category and link
+        matches
+            .name("ws2")
+            .map_or((" ", 0), |m| whitespace_with_column(m, 0, get_column)),
+        whitespace_with_column(first_link.name("ws3").unwrap(), links_start, get_column),
+        whitespace_with_column(matches.name("ws4").unwrap(), 0, get_column),
     ];
 
-    let mut problems: Vec<String> = Vec::new();
+    let mut problems: Vec<Problem> = Vec::new();
+    let mut problem_columns: BTreeMap<usize, usize> = BTreeMap::new();
 
-    check_whitespace(spaces)
-        .into_iter()
-        .for_each(|p| problems.push(p));
+    for (p, column) in check_whitespace(spaces) {
+        problem_columns.insert(problems.len(), column);
+        problems.push(Problem::warning(p));
+    }
 
-    let (fixed_category, category_problems) = check_category(config, category);
-    category_problems.into_iter().for_each(|p| problems.push(p));
+    // NOTE: additional PR links are expected to be separated by a single space
+    for link in links.iter().skip(1) {
+        let (sep, column) =
+            whitespace_with_column(link.name("sep").unwrap(), links_start, get_column);
+        if sep != " " {
+            problem_columns.insert(problems.len(), column);
+            problems.push(Problem::warning(
+                "There should be exactly one space between the PR links",
+            ))
+        }
 
-    if matches.name("bs").is_some() {
-        problems.push("There should be no backslash in front of the # in the PR link".to_string());
+        let (ws3, column) =
+            whitespace_with_column(link.name("ws3").unwrap(), links_start, get_column);
+        if !ws3.is_empty() {
+            problem_columns.insert(problems.len(), column);
+            problems.push(Problem::warning(
+                "There should be no whitespace inside of the markdown link",
+            ))
+        }
     }
 
-    let (fixed_link, link_problems) = check_link(config, link, pr_number);
-    link_problems.into_iter().for_each(|p| problems.push(p));
+    let fixed_category = match (config.use_categories, category) {
+        (true, Some(c)) => {
+            let (fixed, category_problems) = check_category(config, c);
+            category_problems.into_iter().for_each(|p| problems.push(p));
+            fixed
+        }
+        (false, Some(c)) => {
+            problems.push(Problem::warning(format!(
+                "entry should not contain a category: ({c})"
+            )));
+            String::new()
+        }
+        _ => String::new(),
+    };
+
+    let mut fixed_links: Vec<(u64, String)> = Vec::new();
+    for (link, pr_number) in links.iter().zip(pr_numbers.iter()) {
+        match (link.name("bs").is_some(), config.escape_pr_hash) {
+            (true, false) => problems.push(Problem::warning(
+                "There should be no backslash in front of the # in the PR link",
+            )),
+            (false, true) => problems.push(Problem::warning(
+                "There should be a backslash in front of the # in the PR link",
+            )),
+            _ => (),
+        }
+
+        let (fixed_link, link_problems) = match link.name("label") {
+            Some(l) => check_reference_link(config, l.as_str(), references, *pr_number),
+            None => {
+                let (fixed_link, link_problems) =
+                    check_link(config, link.name("link").unwrap().as_str(), *pr_number);
+                (format!("({fixed_link})"), link_problems)
+            }
+        };
+        link_problems.into_iter().for_each(|p| problems.push(p));
+        fixed_links.push((*pr_number, fixed_link));
+    }
+
+    let fixed_mirror_link = match config.mirror_repo {
+        Some(_) => {
+            if matches.name("mirror").is_some() && matches.name("ws5").unwrap().as_str() != " " {
+                problems.push(Problem::warning(
+                    "There should be exactly one space between the PR and mirror links",
+                ));
+            }
+
+            let (fixed_mirror_link, mirror_problems) = check_mirror_link(
+                config,
+                matches.name("mirror_link").map_or("", |m| m.as_str()),
+                pr_numbers[0],
+            );
+            mirror_problems.into_iter().for_each(|p| problems.push(p));
+            Some(fixed_mirror_link)
+        }
+        None => None,
+    };
 
     let (fixed_desc, desc_problems) = check_description(config, description);
     desc_problems.into_iter().for_each(|p| problems.push(p));
 
     let fixed = build_fixed(
-        fixed_category.as_str(),
-        fixed_link.as_str(),
+        config.use_categories.then_some(fixed_category.as_str()),
+        &fixed_links,
+        fixed_mirror_link.as_deref(),
         fixed_desc.as_str(),
-        pr_number,
+        commit_type.as_deref(),
+        config.escape_pr_hash,
     );
 
     Ok(Entry {
         category: fixed_category.to_string(),
+        commit_type,
+        line: original_line,
         fixed,
-        pr_number,
+        pr_number: pr_numbers[0],
+        pr_numbers,
         problems,
+        problem_columns,
+        sub_items: Vec::new(),
     })
 }
 
+/// Returns the matched whitespace together with the column of its start in the line.
+fn whitespace_with_column<'a>(
+    m: regex::Match<'a>,
+    offset: usize,
+    get_column: impl Fn(usize) -> usize,
+) -> (&'a str, usize) {
+    (m.as_str(), get_column(offset + m.start()))
+}
+
 /// Returns the fixed entry string based on the given building parts.
-fn build_fixed(cat: &str, link: &str, desc: &str, pr: u16) -> String {
-    format!("- ({}) [#{}]({}) {}", cat, pr, link, desc,)
+/// The links are given as the rendered link targets, i.e. `(link)` for inline links
+/// and `[label]` for reference-style links.
+///
+/// NOTE: The category is omitted if none is passed. The mirror link is added
+/// after the PR links and the commit type is appended as an HTML comment if they are given.
+/// If configured, the `#` in the PR links is escaped.
+fn build_fixed(
+    cat: Option<&str>,
+    links: &[(u64, String)],
+    mirror_link: Option<&str>,
+    desc: &str,
+    commit_type: Option<&str>,
+    escape_pr_hash: bool,
+) -> String {
+    let hash = match escape_pr_hash {
+        true => "\\#",
+        false => "#",
+    };
+    let mut links = links
+        .iter()
+        .map(|(pr, link)| format!("[{}{}]{}", hash, pr, link))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    if let Some(ml) = mirror_link {
+        links = format!("{links} ([mirror]({ml}))");
+    }
+
+    let fixed = match cat {
+        Some(c) => format!("- ({}) {} {}", c, links, desc),
+        None => format!("- {} {}", links, desc),
+    };
+
+    match commit_type {
+        Some(ct) => format!("{fixed} <!-- clu-commit-type: {ct} -->"),
+        None => fixed,
+    }
+}
+
+/// Splits the optional commit type annotation (e.g. `<!-- clu-commit-type: fix(cli) -->`)
+/// from the end of the given entry line.
+fn split_commit_type(line: &str) -> (&str, Option<String>) {
+    match Regex::new(r"\s*<!--\s*clu-commit-type:\s*(?P<ct>\S+)\s*-->\s*$")
+        .expect("invalid regex pattern")
+        .captures(line)
+    {
+        Some(c) => (
+            &line[..c.get(0).unwrap().start()],
+            Some(c.name("ct").unwrap().as_str().to_string()),
+        ),
+        None => (line, None),
+    }
 }
 
 /// Check if the category is valid and return a fixed version that addresses
 /// well-known problems.
-pub fn check_category(config: &config::Config, category: &str) -> (String, Vec<String>) {
-    let mut problems: Vec<String> = Vec::new();
+pub fn check_category(config: &config::Config, category: &str) -> (String, Vec<Problem>) {
+    let mut problems: Vec<Problem> = Vec::new();
     let fixed = category.to_lowercase();
     if category.to_lowercase() != category {
-        problems.push(format!("category should be lowercase: ({})", category));
+        problems.push(Problem::warning(format!(
+            "category should be lowercase: ({})",
+            category
+        )));
     }
 
     if !config.categories.contains(&fixed) {
-        problems.push(format!("invalid change category: ({})", category));
+        problems.push(Problem::warning(format!(
+            "invalid change category: ({})",
+            category
+        )));
     }
 
     (fixed, problems)
 }
 
 /// Check if the link is valid
-fn check_link(config: &config::Config, link: &str, pr_number: u16) -> (String, Vec<String>) {
-    let mut problems: Vec<String> = Vec::new();
+fn check_link(config: &config::Config, link: &str, pr_number: u64) -> (String, Vec<Problem>) {
+    let mut problems: Vec<Problem> = Vec::new();
 
-    let fixed = format!("{}/pull/{}", config.target_repo, pr_number);
+    let link_base = format!("{}/{}/", config.target_repo, config.forge.pr_path());
+    let fixed = format!("{}{}", link_base, pr_number);
 
     if !link.starts_with(config.target_repo.as_str()) {
-        problems.push(format!("PR link points to wrong repository: {}", link))
+        problems.push(Problem::error(format!(
+            "PR link points to wrong repository: {}",
+            link
+        )))
+    } else if !link.starts_with(link_base.as_str()) {
+        problems.push(Problem::warning(format!(
+            "PR link has wrong path; expected: '{}'; got: '{}'",
+            link_base, link
+        )))
     }
 
     let split_link: Vec<&str> = link.split('/').collect();
-    let contained_pr_number = split_link
+    match split_link
         .last()
         .expect("this should never be empty")
-        .parse::<u16>()
-        .expect("this should always be a u16");
-
-    if contained_pr_number != pr_number {
-        problems.push(format!(
+        .parse::<u64>()
+    {
+        Ok(n) if n != pr_number => problems.push(Problem::error(format!(
             "PR link is not matching PR number {}: '{}'",
             pr_number, link
-        ));
+        ))),
+        Ok(_) => (),
+        Err(_) => problems.push(Problem::error(format!(
+            "PR link does not end in a PR number: '{}'",
+            link
+        ))),
+    }
+
+    (fixed, problems)
+}
+
+/// Checks the link definition referenced by the given label and returns the rendered
+/// link target together with the found problems.
+///
+/// NOTE: If the label is not defined, the entry is fixed to use an inline link instead.
+fn check_reference_link(
+    config: &config::Config,
+    label: &str,
+    references: &BTreeMap<String, String>,
+    pr_number: u64,
+) -> (String, Vec<Problem>) {
+    match references.get(&label.to_lowercase()) {
+        Some(link) => {
+            let (_, problems) = check_link(config, link, pr_number);
+            (format!("[{label}]"), problems)
+        }
+        None => {
+            let fixed_link = format!(
+                "{}/{}/{}",
+                config.target_repo,
+                config.forge.pr_path(),
+                pr_number
+            );
+            (
+                format!("({fixed_link})"),
+                vec![Problem::warning(format!(
+                    "PR link reference is not defined: [{label}]"
+                ))],
+            )
+        }
+    }
+}
+
+/// The pattern to match reference link definitions (e.g. `[pr1]: https://...`).
+pub const LINK_DEFINITION_PATTERN: &str = r"^\s*\[(?P<label>[^\]]+)]:\s*(?P<link>\S+)\s*$";
+
+/// Returns the label and the link of the given reference link definition
+/// (e.g. `[pr1]: https://...`), where the label is returned in lowercase.
+///
+/// NOTE: The regex is compiled from `LINK_DEFINITION_PATTERN` by the caller,
+/// so that it is not rebuilt for every line of the changelog.
+pub fn get_link_definition(link_definition_regex: &Regex, line: &str) -> Option<(String, String)> {
+    link_definition_regex
+        .captures(line)
+        .map(|c| (c["label"].to_lowercase(), c["link"].to_string()))
+}
+
+/// Check if the link to the merge request in the configured mirror repository is valid.
+///
+/// NOTE: This is only called if a mirror repository is configured.
+fn check_mirror_link(
+    config: &config::Config,
+    link: &str,
+    pr_number: u64,
+) -> (String, Vec<Problem>) {
+    let mirror_repo = config.mirror_repo.as_deref().unwrap_or_default();
+    let fixed = format!("{}/merge_requests/{}", mirror_repo, pr_number);
+
+    if link.is_empty() {
+        return (
+            fixed,
+            vec![Problem::warning(format!(
+                "Mirror link is missing for PR {}",
+                pr_number
+            ))],
+        );
+    }
+
+    let mut problems: Vec<Problem> = Vec::new();
+    if !link.starts_with(mirror_repo) {
+        problems.push(Problem::error(format!(
+            "Mirror link points to wrong repository: {}",
+            link
+        )))
+    }
+
+    if link.rsplit('/').next() != Some(pr_number.to_string().as_str()) {
+        problems.push(Problem::warning(format!(
+            "Mirror link is not matching PR number {}: '{}'",
+            pr_number, link
+        )));
     }
 
     (fixed, problems)
 }
 
-pub fn check_description(config: &config::Config, desc: &str) -> (String, Vec<String>) {
+pub fn check_description(config: &config::Config, desc: &str) -> (String, Vec<Problem>) {
     let mut fixed = desc.to_string();
-    let mut problems: Vec<String> = Vec::new();
+    let mut problems: Vec<Problem> = Vec::new();
 
     let first_letter = desc.chars().next().expect("no character in description");
     if first_letter.is_alphabetic() && !first_letter.is_uppercase() {
         fixed = first_letter.to_ascii_uppercase().to_string() + desc.to_owned()[1..].as_ref();
-        problems.push(format!(
+        problems.push(Problem::warning(format!(
             "PR description should start with capital letter: '{}'",
             desc
-        ))
+        )))
+    }
+
+    let trimmed = fixed.trim_end();
+    if trimmed.len() != fixed.len() && !trimmed.is_empty() {
+        problems.push(Problem::info(format!(
+            "PR description should not end with whitespace: '{}'",
+            desc
+        )));
+        fixed = trimmed.to_string();
     }
 
     let last_letter = fixed
@@ -163,22 +504,138 @@ pub fn check_description(config: &config::Config, desc: &str) -> (String, Vec<St
         .expect("no characters found in description");
     if last_letter.to_string() != '.'.to_string() {
         fixed = fixed.to_string() + ".";
-        problems.push(format!("PR description should end with a dot: '{}'", desc))
+        problems.push(Problem::warning(format!(
+            "PR description should end with a dot: '{}'",
+            desc
+        )))
+    } else if fixed.ends_with("..") {
+        fixed = fixed.trim_end_matches('.').to_string() + ".";
+        problems.push(Problem::warning(format!(
+            "PR description should end with a single dot: '{}'",
+            desc
+        )))
     }
 
     let (fixed, spelling_problems) = check_spelling(config, fixed.as_str());
     spelling_problems.into_iter().for_each(|p| problems.push(p));
 
+    if let Some(p) = check_imperative(config, fixed.as_str()) {
+        problems.push(Problem::warning(p))
+    }
+
+    if let Some(p) = check_length(config, fixed.as_str()) {
+        problems.push(Problem::warning(p))
+    }
+
+    check_forbidden_terms(config, fixed.as_str())
+        .into_iter()
+        .for_each(|p| problems.push(Problem::warning(p)));
+
     (fixed, problems)
 }
 
+/// Checks if the description contains any of the configured forbidden terms.
+///
+/// NOTE: Terms inside of code blocks or within another word are ignored.
+fn check_forbidden_terms(config: &config::Config, desc: &str) -> Vec<String> {
+    config
+        .forbidden_terms
+        .iter()
+        .filter(|term| {
+            get_spelling_match(
+                regex::escape(term).as_str(),
+                desc,
+                config.spelling_boundary.as_str(),
+            )
+            .is_ok()
+        })
+        .map(|term| format!("description contains forbidden term: '{term}'"))
+        .collect()
+}
+
+/// Checks if the description exceeds the configured maximum length.
+///
+/// NOTE: Markdown links are only counted with their link text.
+fn check_length(config: &config::Config, desc: &str) -> Option<String> {
+    let max_length = config.max_description_length?;
+
+    let length = Regex::new(r"\[(?P<text>[^\]]*)]\([^)]*\)")
+        .expect("invalid regex pattern")
+        .replace_all(desc, "$text")
+        .chars()
+        .count();
+
+    if length <= max_length {
+        return None;
+    }
+
+    Some(format!(
+        "PR description exceeds the maximum length of {max_length} characters: {length}"
+    ))
+}
+
+/// Checks if the description starts with a word from the configured blocklist
+/// for non-imperative verbs.
+///
+/// NOTE: Descriptions starting with a code block are not checked.
+fn check_imperative(config: &config::Config, desc: &str) -> Option<String> {
+    if desc.starts_with('`') {
+        return None;
+    }
+
+    let first_word = desc
+        .split_whitespace()
+        .next()?
+        .trim_end_matches(|c: char| !c.is_alphanumeric());
+
+    if !config
+        .imperative_blocklist
+        .iter()
+        .any(|w| w.eq_ignore_ascii_case(first_word))
+    {
+        return None;
+    }
+
+    match get_imperative_form(first_word) {
+        Some(imperative) => Some(format!(
+            "PR description should use imperative mood: '{imperative}' instead of '{first_word}'"
+        )),
+        None => Some(format!(
+            "PR description should use imperative mood instead of '{first_word}'"
+        )),
+    }
+}
+
+/// Returns the imperative form for a set of commonly used verbs.
+fn get_imperative_form(word: &str) -> Option<&'static str> {
+    let imperatives = [
+        "Add",
+        "Bump",
+        "Change",
+        "Fix",
+        "Implement",
+        "Improve",
+        "Refactor",
+        "Remove",
+        "Update",
+    ];
+
+    imperatives.into_iter().find(|imperative| {
+        let base = imperative.to_lowercase();
+        let stem = base.trim_end_matches('e');
+        let word = word.to_lowercase();
+
+        [format!("{stem}ed"), format!("{base}s"), format!("{base}es")].contains(&word)
+    })
+}
+
 /// Checks the spelling of entries according to the given configuration.
-fn check_spelling(config: &config::Config, text: &str) -> (String, Vec<String>) {
+pub fn check_spelling(config: &config::Config, text: &str) -> (String, Vec<Problem>) {
     let mut fixed = text.to_string();
-    let mut problems: Vec<String> = Vec::new();
+    let mut problems: Vec<Problem> = Vec::new();
 
     for (correct_spelling, pattern) in config.expected_spellings.iter() {
-        match get_spelling_match(pattern, text) {
+        match get_spelling_match(pattern, text, config.spelling_boundary.as_str()) {
             Ok(m) => {
                 if m.eq(correct_spelling) {
                     continue;
@@ -194,9 +651,9 @@ fn check_spelling(config: &config::Config, text: &str) -> (String, Vec<String>)
                     .replace(fixed.as_str(), correct_spelling)
                     .to_string();
 
-                problems.push(format!(
+                problems.push(Problem::warning(format!(
                     "'{correct_spelling}' should be used instead of '{m}'",
-                ))
+                )))
             }
             Err(_) => continue,
         }
@@ -207,13 +664,16 @@ fn check_spelling(config: &config::Config, text: &str) -> (String, Vec<String>)
 
 /// Compiles the regular expression pattern with the common settings
 /// used in this crate.
-fn compile_regex(pattern: &str) -> Result<Regex, Error> {
+pub(crate) fn compile_regex(pattern: &str) -> Result<Regex, Error> {
     RegexBuilder::new(pattern).case_insensitive(true).build()
 }
 
 /// Returns the first match of the given pattern in the text.
 /// Matching patterns inside of code blocks, links or within another word are ignored.
-fn get_spelling_match(pattern: &str, text: &str) -> Result<String, MatchError> {
+///
+/// The given boundary characters are accepted after the match in addition to
+/// whitespace and periods.
+fn get_spelling_match(pattern: &str, text: &str, boundary: &str) -> Result<String, MatchError> {
     // Check if pattern is inside a code block
     if RegexBuilder::new(format!(r"`[^`]*({pattern})[^`]*`").as_str())
         .case_insensitive(true)
@@ -225,7 +685,8 @@ fn get_spelling_match(pattern: &str, text: &str) -> Result<String, MatchError> {
     }
 
     // Check isolated words (i.e. pattern is not included in another word)
-    match RegexBuilder::new(format!(r"(^|\s)({pattern})($|[\s.])").as_str())
+    let boundary = regex::escape(boundary);
+    match RegexBuilder::new(format!(r"(^|\s)({pattern})($|[\s.{boundary}])").as_str())
         .case_insensitive(true)
         .build()?
         .captures(text)
@@ -239,8 +700,11 @@ fn get_spelling_match(pattern: &str, text: &str) -> Result<String, MatchError> {
 }
 
 /// Checks the used whitespace in the entry.
-fn check_whitespace(spaces: [&str; 5]) -> Vec<String> {
-    let mut problems: Vec<String> = Vec::new();
+///
+/// Each whitespace is passed with the column of its start in the line,
+/// which is returned together with the corresponding problem.
+fn check_whitespace(spaces: [(&str, usize); 5]) -> Vec<(String, usize)> {
+    let mut problems: Vec<(String, usize)> = Vec::new();
 
     let expected_whitespace = ["", " ", " ", "", " "];
     let errors = [
@@ -251,13 +715,11 @@ fn check_whitespace(spaces: [&str; 5]) -> Vec<String> {
         "There should be exactly one space between the PR link and the description",
     ];
 
-    for ((got, expected), error) in spaces
-        .into_iter()
-        .zip(expected_whitespace.into_iter())
-        .zip(errors.into_iter())
+    for (((got, column), expected), error) in
+        spaces.into_iter().zip(expected_whitespace).zip(errors)
     {
-        if (*got).ne(expected) {
-            problems.push(error.to_string())
+        if got.ne(expected) {
+            problems.push((error.to_string(), column))
         }
     }
 
@@ -304,6 +766,45 @@ mod entry_tests {
         );
     }
 
+    #[test]
+    fn test_pass_escaped_hash_if_configured() {
+        let mut config = load_test_config();
+        config.escape_pr_hash = true;
+
+        let example =
+            r"- (cli) [\#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Test.";
+        let entry = parse(&config, example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, example);
+        assert!(entry.problems.is_empty());
+    }
+
+    #[test]
+    fn test_fail_missing_backslash_if_configured() {
+        let mut config = load_test_config();
+        config.escape_pr_hash = true;
+
+        let example =
+            r"- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Test.";
+        let entry = parse(&config, example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, example.replace("#", r"\#"));
+        assert_eq!(
+            entry.problems,
+            ["There should be a backslash in front of the # in the PR link"]
+        );
+    }
+
+    #[test]
+    fn test_new_entry_with_escaped_hash() {
+        let mut config = load_test_config();
+        config.escape_pr_hash = true;
+
+        let entry = Entry::new(&config, "cli", "Test.", 1, None);
+        assert_eq!(
+            entry.fixed,
+            r"- (cli) [\#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Test."
+        );
+    }
+
     #[test]
     fn test_fail_wrong_pr_link_and_missing_dot() {
         let example = r"- (cli) [#2](https://github.com/MalteHerrmann/changelog-utils/pull/1) Test";
@@ -326,6 +827,109 @@ mod entry_tests {
         );
     }
 
+    #[test]
+    fn test_fail_missing_category() {
+        let example = "- [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Test.";
+        assert!(parse(&load_test_config(), example).is_err());
+    }
+
+    #[test]
+    fn test_pass_without_categories() {
+        let mut config = load_test_config();
+        config.use_categories = false;
+
+        let example = "- [#5](https://github.com/MalteHerrmann/changelog-utils/pull/5) Fix bug.";
+        let entry = parse(&config, example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, example);
+        assert_eq!(entry.pr_number, 5);
+        assert_eq!(entry.category, "");
+        assert!(entry.problems.is_empty());
+    }
+
+    #[test]
+    fn test_fail_category_when_not_using_categories() {
+        let mut config = load_test_config();
+        config.use_categories = false;
+
+        let example =
+            "- (cli) [#5](https://github.com/MalteHerrmann/changelog-utils/pull/5) Fix bug.";
+        let entry = parse(&config, example).expect("failed to parse entry");
+        assert_eq!(
+            entry.fixed,
+            "- [#5](https://github.com/MalteHerrmann/changelog-utils/pull/5) Fix bug."
+        );
+        assert_eq!(
+            entry.problems,
+            ["entry should not contain a category: (cli)"]
+        );
+    }
+
+    #[test]
+    fn test_pass_with_commit_type() {
+        let example = concat!(
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+            "Add initial Python implementation. <!-- clu-commit-type: feat(cli) -->"
+        );
+        let entry = parse(&load_test_config(), example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, example);
+        assert_eq!(entry.commit_type, Some("feat(cli)".to_string()));
+        assert!(entry.problems.is_empty());
+    }
+
+    #[test]
+    fn test_new_with_commit_type() {
+        let entry = Entry::new(&load_test_config(), "cli", "Fix bug.", 3, Some("fix(cli)"));
+        assert_eq!(
+            entry.fixed,
+            concat!(
+                "- (cli) [#3](https://github.com/MalteHerrmann/changelog-utils/pull/3) ",
+                "Fix bug. <!-- clu-commit-type: fix(cli) -->"
+            )
+        );
+
+        let parsed = parse(&load_test_config(), entry.fixed.as_str()).expect("failed to parse");
+        assert_eq!(parsed.commit_type, Some("fix(cli)".to_string()));
+        assert_eq!(parsed.fixed, entry.fixed);
+    }
+
+    #[test]
+    fn test_pass_multiple_links() {
+        let example = concat!(
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+            "[#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix thing."
+        );
+        let entry = parse(&load_test_config(), example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, example);
+        assert_eq!(entry.pr_number, 1);
+        assert_eq!(entry.pr_numbers, vec![1, 2]);
+        assert!(entry.problems.is_empty());
+    }
+
+    #[test]
+    fn test_fail_multiple_links_with_mismatch() {
+        let example = concat!(
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1)  ",
+            "[#2](https://github.com/MalteHerrmann/changelog-utils/pull/3) Fix thing."
+        );
+        let expected = concat!(
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+            "[#2](https://github.com/MalteHerrmann/changelog-utils/pull/2) Fix thing."
+        );
+        let entry = parse(&load_test_config(), example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, expected);
+        assert_eq!(entry.pr_numbers, vec![1, 2]);
+        assert_eq!(
+            entry.problems,
+            vec![
+                "There should be exactly one space between the PR links",
+                concat!(
+                    "PR link is not matching PR number 2: ",
+                    "'https://github.com/MalteHerrmann/changelog-utils/pull/3'"
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_malformed_entry() {
         let example = r"- (cli) [#13tps://github.com/Ma/2";
@@ -352,6 +956,30 @@ mod entry_tests {
             ]
         );
     }
+
+    #[test]
+    fn test_column_of_leading_space() {
+        let example =
+            r" - (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Run test.";
+        let entry = parse(&load_test_config(), example).expect("failed to parse entry");
+        assert_eq!(
+            entry.problems,
+            ["There should be no leading whitespace before the dash"]
+        );
+        assert_eq!(entry.problem_columns.get(&0), Some(&1));
+    }
+
+    #[test]
+    fn test_column_of_space_in_link() {
+        let example =
+            r"- (cli) [#1] (https://github.com/MalteHerrmann/changelog-utils/pull/1) Run test.";
+        let entry = parse(&load_test_config(), example).expect("failed to parse entry");
+        assert_eq!(
+            entry.problems,
+            ["There should be no whitespace inside of the markdown link"]
+        );
+        assert_eq!(entry.problem_columns.get(&0), Some(&13));
+    }
 }
 
 #[cfg(test)]
@@ -378,6 +1006,79 @@ mod category_tests {
         assert_eq!(fixed, "cli");
         assert_eq!(problems, ["category should be lowercase: (cLi)"]);
     }
+
+    #[test]
+    fn test_pass_reference_link() {
+        let example = "- (cli) [#1][pr1] Add initial Python implementation.";
+        let references = BTreeMap::from([(
+            "pr1".to_string(),
+            "https://github.com/MalteHerrmann/changelog-utils/pull/1".to_string(),
+        )]);
+        let entry = parse_with_references(&load_test_config(), example, &references)
+            .expect("failed to parse entry");
+        assert!(entry.problems.is_empty());
+        assert_eq!(entry.pr_numbers, vec![1]);
+        assert_eq!(entry.fixed, example);
+    }
+
+    #[test]
+    fn test_fail_reference_link_wrong_repo() {
+        let example = "- (cli) [#1][PR1] Add initial Python implementation.";
+        let references = BTreeMap::from([(
+            "pr1".to_string(),
+            "https://github.com/evmos/evmos/pull/1".to_string(),
+        )]);
+        let entry = parse_with_references(&load_test_config(), example, &references)
+            .expect("failed to parse entry");
+        assert_eq!(
+            entry.problems,
+            ["PR link points to wrong repository: https://github.com/evmos/evmos/pull/1"]
+        );
+        assert_eq!(entry.fixed, example);
+    }
+
+    #[test]
+    fn test_fail_reference_link_without_pr_number() {
+        let example = "- (cli) [#1][pr1] Add initial Python implementation.";
+        let references = BTreeMap::from([(
+            "pr1".to_string(),
+            "https://github.com/MalteHerrmann/changelog-utils/pull/1/".to_string(),
+        )]);
+        let entry = parse_with_references(&load_test_config(), example, &references)
+            .expect("failed to parse entry");
+        assert_eq!(
+            entry.problems,
+            ["PR link does not end in a PR number: 'https://github.com/MalteHerrmann/changelog-utils/pull/1/'"]
+        );
+        assert_eq!(entry.fixed, example);
+    }
+
+    #[test]
+    fn test_fail_undefined_reference_link() {
+        let example = "- (cli) [#1][pr1] Add initial Python implementation.";
+        let entry = parse(&load_test_config(), example).expect("failed to parse entry");
+        assert_eq!(entry.problems, ["PR link reference is not defined: [pr1]"]);
+        assert_eq!(
+            entry.fixed,
+            concat!(
+                "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+                "Add initial Python implementation."
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_link_definition() {
+        let regex = Regex::new(LINK_DEFINITION_PATTERN).expect("invalid regex pattern");
+        assert_eq!(
+            get_link_definition(&regex, "[PR1]: https://github.com/evmos/evmos/pull/1"),
+            Some((
+                "pr1".to_string(),
+                "https://github.com/evmos/evmos/pull/1".to_string()
+            ))
+        );
+        assert_eq!(get_link_definition(&regex, "- (cli) [#1][pr1] Desc."), None);
+    }
 }
 
 #[cfg(test)]
@@ -421,6 +1122,46 @@ mod link_tests {
             )]
         );
     }
+
+    #[test]
+    fn test_wrong_path() {
+        let example = r"https://github.com/MalteHerrmann/changelog-utils/issues/1";
+        let (fixed, problems) = check_link(&load_test_config(), example, 1);
+        assert_eq!(fixed, example.replace("issues", "pull"));
+        assert_eq!(
+            problems,
+            vec![format!(
+                "PR link has wrong path; expected: 'https://github.com/MalteHerrmann/changelog-utils/pull/'; got: '{}'",
+                example
+            )]
+        );
+    }
+
+    #[test]
+    fn test_pass_gitlab() {
+        let mut config = load_test_config();
+        config.forge = config::ForgeKind::GitLab;
+        let example = r"https://github.com/MalteHerrmann/changelog-utils/-/merge_requests/1";
+        let (fixed, problems) = check_link(&config, example, 1);
+        assert_eq!(fixed, example);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_gitlab_rejects_github_path() {
+        let mut config = load_test_config();
+        config.forge = config::ForgeKind::GitLab;
+        let example = r"https://github.com/MalteHerrmann/changelog-utils/pull/1";
+        let (fixed, problems) = check_link(&config, example, 1);
+        assert_eq!(fixed, example.replace("pull", "-/merge_requests"));
+        assert_eq!(
+            problems,
+            vec![format!(
+                "PR link has wrong path; expected: 'https://github.com/MalteHerrmann/changelog-utils/-/merge_requests/'; got: '{}'",
+                example
+            )]
+        );
+    }
 }
 
 #[cfg(test)]
@@ -457,6 +1198,41 @@ mod description_tests {
         );
     }
 
+    #[test]
+    fn test_pass_single_dot() {
+        let (fixed, problems) = check_description(&load_test_config(), "Fix bug.");
+        assert_eq!(fixed, "Fix bug.");
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_fail_double_dot() {
+        let example = "Fix bug..";
+        let (fixed, problems) = check_description(&load_test_config(), example);
+        assert_eq!(fixed, "Fix bug.");
+        assert_eq!(
+            problems,
+            vec![format!(
+                "PR description should end with a single dot: '{}'",
+                example
+            )]
+        );
+    }
+
+    #[test]
+    fn test_fail_trailing_whitespace_after_dot() {
+        let example = "Fix bug. ";
+        let (fixed, problems) = check_description(&load_test_config(), example);
+        assert_eq!(fixed, "Fix bug.");
+        assert_eq!(
+            problems,
+            vec![format!(
+                "PR description should not end with whitespace: '{}'",
+                example
+            )]
+        );
+    }
+
     #[test]
     fn test_fail_does_not_end_with_dot() {
         let example = "Add Python implementation";
@@ -472,6 +1248,241 @@ mod description_tests {
     }
 }
 
+#[cfg(test)]
+mod imperative_tests {
+    use super::*;
+
+    fn load_imperative_config() -> config::Config {
+        let mut config = load_test_config();
+        config.imperative_blocklist = vec!["Added".into(), "Fixes".into(), "Did".into()];
+        config
+    }
+
+    #[test]
+    fn test_pass() {
+        let (fixed, problems) = check_description(&load_imperative_config(), "Add support.");
+        assert_eq!(fixed, "Add support.");
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_fail_blocked_word() {
+        let (fixed, problems) = check_description(&load_imperative_config(), "Added support.");
+        assert_eq!(fixed, "Added support.");
+        assert_eq!(
+            problems,
+            ["PR description should use imperative mood: 'Add' instead of 'Added'"]
+        );
+    }
+
+    #[test]
+    fn test_fail_blocked_word_with_suffix() {
+        assert_eq!(
+            check_imperative(&load_imperative_config(), "Fixes bug."),
+            Some("PR description should use imperative mood: 'Fix' instead of 'Fixes'".into())
+        );
+    }
+
+    #[test]
+    fn test_fail_blocked_word_without_known_imperative() {
+        assert_eq!(
+            check_imperative(&load_imperative_config(), "Did something."),
+            Some("PR description should use imperative mood instead of 'Did'".into())
+        );
+    }
+
+    #[test]
+    fn test_pass_codeblock() {
+        let (_, problems) =
+            check_description(&load_imperative_config(), "`Added` method was renamed.");
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_pass_empty_blocklist() {
+        let (_, problems) = check_description(&load_test_config(), "Added support.");
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_imperative_forms() {
+        assert_eq!(get_imperative_form("Added"), Some("Add"));
+        assert_eq!(get_imperative_form("Removed"), Some("Remove"));
+        assert_eq!(get_imperative_form("updates"), Some("Update"));
+        assert_eq!(get_imperative_form("Fixes"), Some("Fix"));
+        assert_eq!(get_imperative_form("Run"), None);
+    }
+}
+
+#[cfg(test)]
+mod length_tests {
+    use super::*;
+
+    fn load_length_config() -> config::Config {
+        let mut config = load_test_config();
+        config.max_description_length = Some(50);
+        config
+    }
+
+    #[test]
+    fn test_pass() {
+        let example = "Add short description.";
+        let (fixed, problems) = check_description(&load_length_config(), example);
+        assert_eq!(fixed, example);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_fail_too_long() {
+        let example = "Add a description that is way too long to be accepted in the changelog.";
+        let (fixed, problems) = check_description(&load_length_config(), example);
+        assert_eq!(fixed, example);
+        assert_eq!(
+            problems,
+            ["PR description exceeds the maximum length of 50 characters: 71"]
+        );
+    }
+
+    #[test]
+    fn test_pass_link_counts_text_only() {
+        let example = "Add [link](https://github.com/MalteHerrmann/changelog-utils).";
+        let (_, problems) = check_description(&load_length_config(), example);
+        assert!(problems.is_empty(), "expected no problems: {:?}", problems);
+    }
+
+    #[test]
+    fn test_pass_no_limit() {
+        let example = "Add a description that is way too long to be accepted in the changelog.";
+        let (_, problems) = check_description(&load_test_config(), example);
+        assert!(problems.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod forbidden_terms_tests {
+    use super::*;
+
+    fn load_forbidden_terms_config() -> config::Config {
+        let mut config = load_test_config();
+        config.forbidden_terms = vec!["WIP".into(), "secret project".into()];
+        config
+    }
+
+    #[test]
+    fn test_pass() {
+        let example = "Add new feature.";
+        let (fixed, problems) = check_description(&load_forbidden_terms_config(), example);
+        assert_eq!(fixed, example);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_fail_forbidden_term() {
+        let example = "Add wip feature for the secret project.";
+        let (fixed, problems) = check_description(&load_forbidden_terms_config(), example);
+        assert_eq!(fixed, example);
+        assert_eq!(
+            problems,
+            [
+                "description contains forbidden term: 'WIP'",
+                "description contains forbidden term: 'secret project'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pass_forbidden_term_in_code_block() {
+        let example = "Add `WIP` label handling.";
+        let (_, problems) = check_description(&load_forbidden_terms_config(), example);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_pass_forbidden_term_in_other_word() {
+        let example = "Add wiping of the cache.";
+        let (_, problems) = check_description(&load_forbidden_terms_config(), example);
+        assert!(problems.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod mirror_tests {
+    use super::*;
+
+    fn load_mirror_config() -> config::Config {
+        let mut config = load_test_config();
+        config.mirror_repo = Some("https://gitlab.example.com/mirror/changelog-utils".into());
+        config
+    }
+
+    #[test]
+    fn test_pass() {
+        let example = concat!(
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+            "([mirror](https://gitlab.example.com/mirror/changelog-utils/merge_requests/1)) ",
+            "Add mirror links."
+        );
+        let entry = parse(&load_mirror_config(), example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, example);
+        assert!(entry.problems.is_empty(), "{:?}", entry.problems);
+    }
+
+    #[test]
+    fn test_new_entry_renders_mirror_link() {
+        let entry = Entry::new(&load_mirror_config(), "cli", "Add mirror links.", 1, None);
+        assert_eq!(
+            entry.fixed,
+            concat!(
+                "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+                "([mirror](https://gitlab.example.com/mirror/changelog-utils/merge_requests/1)) ",
+                "Add mirror links."
+            )
+        );
+    }
+
+    #[test]
+    fn test_fail_missing_mirror_link() {
+        let example =
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Add mirror links.";
+        let entry = parse(&load_mirror_config(), example).expect("failed to parse entry");
+        assert_eq!(
+            entry.fixed,
+            concat!(
+                "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+                "([mirror](https://gitlab.example.com/mirror/changelog-utils/merge_requests/1)) ",
+                "Add mirror links."
+            )
+        );
+        assert_eq!(entry.problems, ["Mirror link is missing for PR 1"]);
+    }
+
+    #[test]
+    fn test_fail_wrong_mirror_link() {
+        let example = concat!(
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) ",
+            "([mirror](https://gitlab.example.com/other/merge_requests/2)) ",
+            "Add mirror links."
+        );
+        let entry = parse(&load_mirror_config(), example).expect("failed to parse entry");
+        assert_eq!(
+            entry.problems,
+            [
+                "Mirror link points to wrong repository: https://gitlab.example.com/other/merge_requests/2",
+                "Mirror link is not matching PR number 1: 'https://gitlab.example.com/other/merge_requests/2'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pass_without_mirror_config() {
+        let example =
+            "- (cli) [#1](https://github.com/MalteHerrmann/changelog-utils/pull/1) Add mirror links.";
+        let entry = parse(&load_test_config(), example).expect("failed to parse entry");
+        assert_eq!(entry.fixed, example);
+        assert!(entry.problems.is_empty());
+    }
+}
+
 #[cfg(test)]
 mod spelling_tests {
     use super::*;
@@ -510,6 +1521,21 @@ mod spelling_tests {
         assert!(problems.is_empty());
     }
 
+    #[test]
+    fn test_spelling_boundary() {
+        let mut config = load_test_config();
+        config.spelling_boundary = ",;:)".to_string();
+
+        let (fixed, problems) = check_spelling(&config, "Fix api, and more.");
+        assert_eq!(fixed, "Fix API, and more.");
+        assert_eq!(problems, ["'API' should be used instead of 'api'"]);
+
+        let example = "Fix `api, in codeblocks`.";
+        let (fixed, problems) = check_spelling(&config, example);
+        assert_eq!(fixed, example);
+        assert!(problems.is_empty());
+    }
+
     #[test]
     fn test_pass_nested_word() {
         let example = "FixApI in another word.";
@@ -525,7 +1551,7 @@ mod match_tests {
 
     #[test]
     fn test_pass() {
-        let found_res = get_spelling_match("api", "Fix API.");
+        let found_res = get_spelling_match("api", "Fix API.", "");
         assert!(found_res.is_ok());
         let found = found_res.unwrap();
         assert_eq!(found, "API");
@@ -533,21 +1559,32 @@ mod match_tests {
 
     #[test]
     fn test_ignore_inside_codeblocks() {
-        let found_err = get_spelling_match("api", "Fix `aPi in codeblocks`.")
+        let found_err = get_spelling_match("api", "Fix `aPi in codeblocks`.", "")
             .expect_err("expected match in code block");
         assert_eq!(found_err, MatchError::MatchInCodeblock);
     }
 
     #[test]
     fn test_ignore_in_word() {
-        let found_err = get_spelling_match("api", "FixApI in word.")
+        let found_err = get_spelling_match("api", "FixApI in word.", "")
+            .expect_err("expected no match found error");
+        assert_eq!(found_err, MatchError::NoMatchFound);
+    }
+
+    #[test]
+    fn test_boundary() {
+        let found_err = get_spelling_match("api", "Fix api, and more.", "")
             .expect_err("expected no match found error");
         assert_eq!(found_err, MatchError::NoMatchFound);
+
+        let found = get_spelling_match("api", "Fix api, and more.", ",;:)")
+            .expect("expected match with additional boundary");
+        assert_eq!(found, "api");
     }
 
     #[test]
     fn test_ignore_in_link() {
-        let found_err = get_spelling_match("api", "Fix [abcdef](https://example/aPi.com)")
+        let found_err = get_spelling_match("api", "Fix [abcdef](https://example/aPi.com)", "")
             .expect_err("expected no match found error");
         assert_eq!(found_err, MatchError::NoMatchFound);
     }
@@ -557,18 +1594,25 @@ mod match_tests {
 mod whitespace_tests {
     use super::*;
 
+    fn with_columns(spaces: [&str; 5]) -> [(&str, usize); 5] {
+        spaces.map(|s| (s, 1))
+    }
+
     #[test]
     fn test_pass() {
         let example_spaces = ["", " ", " ", "", " "];
-        assert!(check_whitespace(example_spaces).is_empty());
+        assert!(check_whitespace(with_columns(example_spaces)).is_empty());
     }
 
     #[test]
     fn test_fail_leading_space() {
         let example_spaces = [" ", " ", " ", "", " "];
         assert_eq!(
-            check_whitespace(example_spaces),
-            ["There should be no leading whitespace before the dash"]
+            check_whitespace(with_columns(example_spaces)),
+            [(
+                "There should be no leading whitespace before the dash".to_string(),
+                1
+            )]
         );
     }
 
@@ -576,8 +1620,12 @@ mod whitespace_tests {
     fn test_fail_space_between_category_and_link() {
         let example_spaces = ["", " ", "", "", " "];
         assert_eq!(
-            check_whitespace(example_spaces),
-            ["There should be exactly one space between the category and the PR link"]
+            check_whitespace(with_columns(example_spaces)),
+            [(
+                "There should be exactly one space between the category and the PR link"
+                    .to_string(),
+                1
+            )]
         );
     }
 
@@ -585,8 +1633,12 @@ mod whitespace_tests {
     fn test_fail_multiple_spaces() {
         let example_spaces = ["", "", " ", "", " "];
         assert_eq!(
-            check_whitespace(example_spaces),
-            ["There should be exactly one space between the leading dash and the category"]
+            check_whitespace(with_columns(example_spaces)),
+            [(
+                "There should be exactly one space between the leading dash and the category"
+                    .to_string(),
+                1
+            )]
         );
     }
 
@@ -594,8 +1646,12 @@ mod whitespace_tests {
     fn test_fail_multiple_spaces_before_description() {
         let example_spaces = ["", " ", " ", "", "  "];
         assert_eq!(
-            check_whitespace(example_spaces),
-            ["There should be exactly one space between the PR link and the description"]
+            check_whitespace(with_columns(example_spaces)),
+            [(
+                "There should be exactly one space between the PR link and the description"
+                    .to_string(),
+                1
+            )]
         );
     }
 
@@ -603,8 +1659,11 @@ mod whitespace_tests {
     fn test_fail_space_in_link() {
         let example_spaces = ["", " ", " ", " ", " "];
         assert_eq!(
-            check_whitespace(example_spaces),
-            ["There should be no whitespace inside of the markdown link"]
+            check_whitespace(with_columns(example_spaces)),
+            [(
+                "There should be no whitespace inside of the markdown link".to_string(),
+                1
+            )]
         );
     }
 }
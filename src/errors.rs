@@ -8,6 +8,8 @@ use thiserror::Error;
 pub enum CLIError {
     #[error("failed to add changelog entry: {0}")]
     AddError(#[from] AddError),
+    #[error("failed to check setup: {0}")]
+    CheckError(#[from] CheckError),
     #[error("failed to create pr: {0}")]
     CreateError(#[from] CreateError),
     #[error("failed to initialize the changelog settings: {0}")]
@@ -18,14 +20,36 @@ pub enum CLIError {
     Config(#[from] ConfigError),
     #[error("failed to adjust configuration: {0}")]
     ConfigAdjustment(#[from] ConfigAdjustError),
+    #[error("failed to update changelog: {0}")]
+    Changelog(#[from] ChangelogError),
     #[error("failed to read/write: {0}")]
     IOError(#[from] io::Error),
+    #[error("failed to get path: {0}")]
+    PathError(#[from] PathError),
     #[error("failed to create new release in changelog: {0}")]
     ReleaseCLIError(#[from] ReleaseCLIError),
+    #[error("failed to apply fixes: {0}")]
+    FixError(#[from] FixError),
+    #[error("failed to move entry: {0}")]
+    MoveError(#[from] MoveError),
+    #[error("failed to remove entry: {0}")]
+    RemoveError(#[from] RemoveError),
+    #[error("failed to get changelog statistics: {0}")]
+    StatsError(#[from] StatsError),
+    #[error("failed to learn categories: {0}")]
+    LearnCategoriesError(#[from] LearnCategoriesError),
+    #[error("failed to get release: {0}")]
+    GetError(#[from] GetError),
+    #[error("failed to remove duplicate entries: {0}")]
+    DedupeError(#[from] DedupeError),
+    #[error("failed to verify links: {0}")]
+    VerifyLinksError(#[from] VerifyLinksError),
 }
 
 #[derive(Error, Debug)]
 pub enum CreateError {
+    #[error("failed to add changelog entry: {0}")]
+    AddEntry(#[from] AddError),
     #[error("branch not found on remote: {0}")]
     BranchNotOnRemote(String),
     #[error("failed to read configuration: {0}")]
@@ -33,11 +57,21 @@ pub enum CreateError {
     #[error("found an existing PR for this branch: {0}")]
     ExistingPR(u64),
     #[error("failed to create PR: {0}")]
-    FailedToCreatePR(#[from] octocrab::Error),
+    FailedToCreatePR(#[from] Box<octocrab::Error>),
     #[error("error interacting with GitHub: {0}")]
     GitHub(#[from] GitHubError),
     #[error("error getting user input: {0}")]
     Input(#[from] InputError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+    #[error("cannot create a pull request in offline mode")]
+    Offline,
+}
+
+impl From<octocrab::Error> for CreateError {
+    fn from(err: octocrab::Error) -> Self {
+        CreateError::FailedToCreatePR(Box::new(err))
+    }
 }
 
 #[derive(Error, Debug)]
@@ -58,6 +92,10 @@ pub enum AddError {
     Input(#[from] InputError),
     #[error("first release is not unreleased section: {0}")]
     FirstReleaseNotUnreleased(String),
+    #[error("'{0}' is not a configured category")]
+    InvalidCategory(String),
+    #[error("'{0}' is not a configured change type")]
+    InvalidChangeType(String),
     #[error("failed to get pull request information: {0}")]
     PRInfo(#[from] GitHubError),
     #[error("failed to parse changelog: {0}")]
@@ -66,6 +104,40 @@ pub enum AddError {
     ReadWriteError(#[from] io::Error),
 }
 
+#[derive(Error, Debug)]
+pub enum BaselineError {
+    #[error("failed to read/write baseline: {0}")]
+    ReadWrite(#[from] io::Error),
+    #[error("failed to (de-)serialize baseline: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum CheckError {
+    #[error("{0} check(s) failed")]
+    Failed(usize),
+    #[error("failed to read/write: {0}")]
+    ReadWrite(#[from] io::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum FixError {
+    #[error("changelog is not fixed")]
+    ChangesRequired,
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to get user input: {0}")]
+    Input(#[from] InputError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+    #[error("invalid date: {0}")]
+    InvalidDate(String),
+    #[error("failed to run linter: {0}")]
+    Lint(#[from] LintError),
+    #[error("failed to read changelog: {0}")]
+    Read(#[from] io::Error),
+}
+
 #[derive(Error, Debug)]
 pub enum InitError {
     #[error("failed to write: {0}")]
@@ -76,22 +148,44 @@ pub enum InitError {
     ConfigError(#[from] ConfigError),
     #[error("failed to get origin")]
     OriginError(#[from] GitHubError),
+    #[error("workflow already exists: {0}")]
+    WorkflowAlreadyFound(String),
 }
 
 #[derive(Error, Debug)]
 pub enum LintError {
+    #[error("failed to handle baseline: {0}")]
+    Baseline(#[from] BaselineError),
+    #[error("changelog contains unresolved merge conflict markers")]
+    ConflictMarkers,
     #[error("failed to parse changelog: {0}")]
     InvalidChangelog(#[from] ChangelogError),
     #[error("invalid configuration: {0}")]
     InvalidConfig(#[from] ConfigError),
+    #[error("invalid version: {0}")]
+    InvalidVersion(#[from] VersionError),
     #[error("found problems in changelog")]
     ProblemsInChangelog,
     #[error("failed to read file system: {0}")]
     Read(#[from] io::Error),
 }
 
+#[derive(Error, Debug)]
+pub enum PathError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to find changelog: {0}")]
+    Changelog(#[from] ChangelogError),
+    #[error("no configuration found in directory")]
+    NoConfigFound,
+    #[error("failed to read file system: {0}")]
+    Read(#[from] io::Error),
+}
+
 #[derive(Error, Debug)]
 pub enum ChangelogError {
+    #[error("changelog contains unresolved merge conflict markers")]
+    ConflictMarkers,
     #[error("failed to parse change type: {0}")]
     InvalidChangeType(#[from] ChangeTypeError),
     #[error("failed to parse entry: {0}")]
@@ -118,14 +212,18 @@ pub enum EntryError {
 pub enum GitHubError {
     #[error("failed to get current branch")]
     CurrentBranch,
+    #[error("failed to determine the default branch")]
+    DefaultBranch,
     #[error("failed to commit changes")]
     FailedToCommit,
     #[error("failed to push to origin")]
     FailedToPush,
     #[error("failed to call GitHub API: {0}")]
-    GitHub(#[from] octocrab::Error),
+    GitHub(#[from] Box<octocrab::Error>),
     #[error("failed to build regex: {0}")]
     InvalidRegex(#[from] Error),
+    #[error("failed to get the last commit")]
+    LastCommit,
     #[error("target repository in configuration is no GitHub repository")]
     NoGitHubRepo,
     #[error("no pull request open for branch")]
@@ -134,14 +232,24 @@ pub enum GitHubError {
     Origin,
     #[error("failed to decode output: {0}")]
     OutputDecoding(#[from] FromUtf8Error),
+    #[error("GitHub API rate limit exceeded after {0} attempts")]
+    RateLimited(u32),
     #[error("failed to match GitHub repo: {0}")]
     RegexMatch(String),
     #[error("failed to execute command: {0}")]
     StdCommand(#[from] io::Error),
-    #[error("GITHUB_TOKEN environment variable not found")]
+    #[error(
+        "GITHUB_TOKEN environment variable not found, which is required for write access to GitHub"
+    )]
     Token(#[from] VarError),
 }
 
+impl From<octocrab::Error> for GitHubError {
+    fn from(err: octocrab::Error) -> Self {
+        GitHubError::GitHub(Box::new(err))
+    }
+}
+
 #[derive(Error, Debug, PartialEq)]
 pub enum MatchError {
     #[error("match is nested inside of code block")]
@@ -158,6 +266,22 @@ pub enum ConfigError {
     FailedToReadWrite(#[from] io::Error),
     #[error("failed to parse configuration")]
     FailedToParse(#[from] serde_json::Error),
+    #[error("failed to parse TOML configuration: {0}")]
+    FailedToParseToml(#[from] toml::de::Error),
+    #[error("failed to serialize TOML configuration: {0}")]
+    FailedToSerializeToml(#[from] toml::ser::Error),
+    #[error("cyclic extension of configuration: {0}")]
+    CyclicExtends(String),
+    #[error("environment variable {0} is set but empty")]
+    EmptyEnvOverride(String),
+    #[error("invalid override from environment: {0}")]
+    InvalidEnvOverride(#[from] ConfigAdjustError),
+    #[error("invalid date format: '{0}'")]
+    InvalidDateFormat(String),
+    #[error("unknown configuration key: {0}")]
+    UnknownKey(String),
+    #[error("found {0} problems in the configuration")]
+    Invalid(usize),
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -170,8 +294,10 @@ pub enum ConfigAdjustError {
     InvalidURL(#[from] url::ParseError),
     #[error("expected value not found")]
     NotFound,
-    #[error("target repository should be a GitHub link")]
-    NoGitHubRepository,
+    #[error("target repository should be hosted on {0}")]
+    UnexpectedHost(String),
+    #[error("spelling '{0}' only differs by case from existing spelling '{1}'")]
+    SpellingCollision(String, String),
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -180,6 +306,8 @@ pub enum ChangeTypeError {
     InvalidRegex(#[from] regex::Error),
     #[error("no matches found")]
     NoMatchesFound,
+    #[error("'{0}' is not a configured change type")]
+    UnknownChangeType(String),
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -202,10 +330,90 @@ pub enum ReleaseCLIError {
     Input(#[from] InputError),
     #[error("failed to parse changelog: {0}")]
     InvalidChangelog(#[from] ChangelogError),
+    #[error("invalid date: {0}")]
+    InvalidDate(String),
     #[error("invalid version: {0}")]
     InvalidVersion(#[from] VersionError),
+    #[error("no prior release found to derive the next version")]
+    NoPriorRelease,
     #[error("no unreleased features")]
     NoUnreleased,
+    #[error("release of version {0} was not confirmed")]
+    NotConfirmed(String),
+    #[error("version {0} is not greater than the existing release {1}")]
+    VersionNotGreater(String, String),
+}
+
+#[derive(Error, Debug)]
+pub enum MoveError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+    #[error("invalid change type: {0}")]
+    InvalidChangeType(String),
+    #[error("failed to remove entry: {0}")]
+    Remove(#[from] RemoveError),
+}
+
+#[derive(Error, Debug)]
+pub enum DedupeError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to get user input: {0}")]
+    Input(#[from] InputError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+}
+
+#[derive(Error, Debug)]
+pub enum RemoveError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+    #[error("no entry with PR number {0} found in unreleased section")]
+    NotFound(u64),
+}
+
+#[derive(Error, Debug)]
+pub enum StatsError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to get GitHub information: {0}")]
+    GitHub(#[from] GitHubError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+    #[error("release not found in changelog: {0}")]
+    ReleaseNotFound(String),
+    #[error("failed to serialize statistics: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+#[derive(Error, Debug)]
+pub enum LearnCategoriesError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to adjust config: {0}")]
+    ConfigAdjust(#[from] ConfigAdjustError),
+    #[error("failed to get GitHub information: {0}")]
+    GitHub(#[from] GitHubError),
+    #[error("failed to get user input: {0}")]
+    Input(#[from] InputError),
+}
+
+#[derive(Error, Debug)]
+pub enum GetError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+    #[error("failed to parse version: {0}")]
+    InvalidVersion(#[from] VersionError),
+    #[error("failed to serialize changelog: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("version not found in changelog: {0}")]
+    VersionNotFound(String),
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -217,3 +425,15 @@ pub enum VersionError {
     #[error("version does not follow semantic versioning")]
     NoMatchFound,
 }
+
+#[derive(Error, Debug)]
+pub enum VerifyLinksError {
+    #[error("failed to load config: {0}")]
+    Config(#[from] ConfigError),
+    #[error("failed to get GitHub information: {0}")]
+    GitHub(#[from] GitHubError),
+    #[error("failed to parse changelog: {0}")]
+    InvalidChangelog(#[from] ChangelogError),
+    #[error("found {0} broken links")]
+    BrokenLinks(usize),
+}
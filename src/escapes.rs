@@ -5,10 +5,18 @@ use regex::Regex;
 pub enum LinterEscape {
     FullLine,
     DuplicatePR,
+    ChangeType,
 }
 
 /// Checks the given comment for an escape pattern.
 pub fn check_escape_pattern(line: &str) -> Option<LinterEscape> {
+    if Regex::new(r"<!--\s*clu-disable-next-change-type(:.+)?\s*-->")
+        .unwrap()
+        .is_match(line)
+    {
+        return Some(LinterEscape::ChangeType);
+    }
+
     if Regex::new(r"<!--\s*clu-disable-next-line-duplicate-pr(:.+)?\s*-->")
         .unwrap()
         .is_match(line)
@@ -68,4 +76,12 @@ mod escape_tests {
             Some(LinterEscape::DuplicatePR)
         );
     }
+
+    #[test]
+    fn test_escape_change_type() {
+        assert_eq!(
+            check_escape_pattern("<!-- clu-disable-next-change-type: legacy section -->"),
+            Some(LinterEscape::ChangeType)
+        );
+    }
 }
@@ -0,0 +1,186 @@
+use crate::{
+    changelog::{self, Changelog},
+    cli::{FixArgs, LintArgs},
+    config,
+    errors::FixError,
+    inputs, lint,
+};
+use chrono::NaiveDate;
+use std::fs;
+
+/// Runs the logic to apply the auto-fixes to the changelog.
+///
+/// In interactive mode, the user is asked for each fixable line whether
+/// the fix should be applied. In check mode, the changes are only printed.
+pub fn run(args: FixArgs) -> Result<(), FixError> {
+    // NOTE: without a date to add, the fixes are written by the linter
+    if !args.interactive && !args.check && !args.stdout && args.date.is_none() {
+        return Ok(lint::run(true, LintArgs::default())?);
+    }
+
+    let config = config::load()?;
+    let mut changelog = changelog::load(config.clone())?;
+
+    if let Some(date) = args.date {
+        if NaiveDate::parse_from_str(date.as_str(), config.get_valid_date_format()?).is_err() {
+            return Err(FixError::InvalidDate(date));
+        }
+
+        add_missing_release_dates(&mut changelog, date.as_str());
+    }
+
+    if args.stdout {
+        print!("{}", changelog.get_fixed_contents());
+        return Ok(());
+    }
+
+    if args.check {
+        let diff = check(&changelog)?;
+        if diff.is_empty() {
+            println!("changelog is already fixed");
+            return Ok(());
+        }
+
+        diff.iter().for_each(|l| println!("{l}"));
+        return Err(FixError::ChangesRequired);
+    }
+
+    if !args.interactive {
+        changelog.write(&changelog.path)?;
+        println!(
+            "automated fixes were applied to {}",
+            changelog.path.to_string_lossy()
+        );
+        return Ok(());
+    }
+
+    let applied = apply_fixes(&mut changelog, inputs::get_permission_to_fix)?;
+    changelog.write(&changelog.path)?;
+
+    println!(
+        "applied {} fixes to {}",
+        applied,
+        changelog.path.to_string_lossy()
+    );
+
+    Ok(())
+}
+
+/// Adds the given date to the fixed headers of all released sections, that are
+/// missing a date, and returns the number of updated releases.
+pub fn add_missing_release_dates(changelog: &mut Changelog, date: &str) -> usize {
+    let mut added = 0;
+
+    for release in changelog.releases.iter_mut() {
+        if release.is_unreleased() || release.date.is_some() {
+            continue;
+        }
+
+        release.fixed = format!("{} - {date}", release.fixed);
+        release.date = Some(date.to_string());
+        added += 1;
+    }
+
+    added
+}
+
+/// Returns the diff between the changelog file on disk and its fixed contents
+/// without writing any changes.
+pub fn check(changelog: &Changelog) -> Result<Vec<String>, FixError> {
+    let original = fs::read_to_string(&changelog.path)?;
+    let fixed = changelog.get_fixed_contents();
+
+    if original.trim_end() == fixed.trim_end() {
+        return Ok(Vec::new());
+    }
+
+    let path = changelog.path.to_string_lossy();
+    let mut diff = vec![format!("--- {path}"), format!("+++ {path} (fixed)")];
+    diff.append(&mut get_diff(original.as_str(), fixed.as_str()));
+
+    Ok(diff)
+}
+
+/// Returns a line-based diff of the given contents, where each group of changed lines
+/// is preceded by a header with the corresponding line numbers.
+pub fn get_diff(original: &str, fixed: &str) -> Vec<String> {
+    let old: Vec<&str> = original.trim_end().lines().collect();
+    let new: Vec<&str> = fixed.trim_end().lines().collect();
+
+    // NOTE: the longest common subsequence is computed from the end of both files,
+    // so that the diff can be built by walking forward afterwards.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = match old[i] == new[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut diff: Vec<String> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut in_hunk = false;
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            in_hunk = false;
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        if !in_hunk {
+            diff.push(format!("@@ -{} +{} @@", i + 1, j + 1));
+            in_hunk = true;
+        }
+
+        if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push(format!("-{}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+{}", new[j]));
+            j += 1;
+        }
+    }
+
+    diff
+}
+
+/// Walks all fixable lines in the changelog and applies the fixes, that are
+/// accepted by the given decision function. Returns the number of applied fixes.
+///
+/// NOTE: Rejected fixes are reverted to the original line, so that they
+/// remain unchanged when exporting the changelog.
+pub fn apply_fixes<F, E>(changelog: &mut Changelog, mut accept: F) -> Result<usize, E>
+where
+    F: FnMut(&str, &str) -> Result<bool, E>,
+{
+    let mut applied = 0;
+    let mut decide = |line: &String, fixed: &mut String| -> Result<(), E> {
+        if line.eq(fixed) {
+            return Ok(());
+        }
+
+        match accept(line, fixed)? {
+            true => applied += 1,
+            false => line.clone_into(fixed),
+        }
+
+        Ok(())
+    };
+
+    for release in changelog.releases.iter_mut() {
+        decide(&release.line, &mut release.fixed)?;
+
+        for change_type in release.change_types.iter_mut() {
+            decide(&change_type.line, &mut change_type.fixed)?;
+
+            for entry in change_type.entries.iter_mut() {
+                decide(&entry.line, &mut entry.fixed)?;
+            }
+        }
+    }
+
+    Ok(applied)
+}
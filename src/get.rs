@@ -0,0 +1,163 @@
+use crate::{
+    changelog::{self, Changelog},
+    cli::GetArgs,
+    config,
+    errors::GetError,
+    pager,
+    release::Release,
+    version::{self, Version},
+};
+use clap::ValueEnum;
+use std::cmp::Ordering;
+
+/// Represents the available output formats for the printed releases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum ReleaseFormat {
+    /// The release section as it is contained in the changelog.
+    #[default]
+    Markdown,
+    /// Plain release notes without the release header, e.g. to be
+    /// used as the body of a GitHub release.
+    ReleaseNotes,
+}
+
+/// Holds the options to format the printed releases.
+#[derive(Clone, Debug, Default)]
+pub struct FormatOptions {
+    pub format: ReleaseFormat,
+    /// Whether to remove the categories from the entries in the release notes.
+    pub strip_categories: bool,
+}
+
+/// Runs the logic to print the contents of the requested releases
+/// in the changelog of the current directory.
+pub fn run(args: GetArgs) -> Result<(), GetError> {
+    let config = config::load()?;
+    let changelog = changelog::load(config)?;
+
+    let options = FormatOptions {
+        format: args.format,
+        strip_categories: args.strip_categories,
+    };
+
+    let output = match (args.since, args.version) {
+        _ if args.json => serde_json::to_string_pretty(&changelog)?,
+        _ if args.all => get_all_releases(&changelog, &options),
+        _ if args.unreleased => get_unreleased(&changelog, &options)?,
+        (Some(since), _) => get_releases_since(&changelog, since.as_str(), &options)?,
+        (None, Some(version)) => get_release(&changelog, version.as_str(), &options)?,
+        (None, None) => unreachable!("either the version, --since or --unreleased is required"),
+    };
+
+    pager::print(output.as_str(), args.no_pager);
+
+    Ok(())
+}
+
+/// Returns the contents of the release with the given version.
+///
+/// NOTE: The literal `unreleased` is matched case-insensitively
+/// and returns the unreleased section.
+pub fn get_release(
+    changelog: &Changelog,
+    version: &str,
+    options: &FormatOptions,
+) -> Result<String, GetError> {
+    if version.eq_ignore_ascii_case("unreleased") {
+        return get_unreleased(changelog, options);
+    }
+
+    match changelog.releases.iter().find(|r| r.version == version) {
+        Some(r) => Ok(format_release(r, options)),
+        None => Err(GetError::VersionNotFound(version.to_string())),
+    }
+}
+
+/// Returns the contents of all releases in the changelog.
+pub fn get_all_releases(changelog: &Changelog, options: &FormatOptions) -> String {
+    changelog
+        .releases
+        .iter()
+        .map(|r| format_release(r, options))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns the contents of the unreleased section.
+pub fn get_unreleased(changelog: &Changelog, options: &FormatOptions) -> Result<String, GetError> {
+    match changelog.releases.iter().find(|r| r.is_unreleased()) {
+        Some(r) => Ok(format_release(r, options)),
+        None => Err(GetError::VersionNotFound("Unreleased".to_string())),
+    }
+}
+
+/// Returns the contents of all releases, that have a higher version
+/// than the given one, sorted from the newest to the oldest release.
+///
+/// NOTE: The unreleased section as well as releases with invalid versions
+/// are not included.
+pub fn get_releases_since(
+    changelog: &Changelog,
+    since: &str,
+    options: &FormatOptions,
+) -> Result<String, GetError> {
+    let since_version = version::parse(since)?;
+
+    let mut releases: Vec<(Version, &Release)> = changelog
+        .releases
+        .iter()
+        .filter_map(|r| version::parse(r.version.as_str()).ok().map(|v| (v, r)))
+        .filter(|(v, _)| v.gt(&since_version))
+        .collect();
+
+    releases.sort_by(|(a, _), (b, _)| {
+        if a.gt(b) {
+            Ordering::Less
+        } else if b.gt(a) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+
+    Ok(releases
+        .iter()
+        .map(|(_, r)| format_release(r, options))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+/// Formats the given release according to the given options.
+fn format_release(release: &Release, options: &FormatOptions) -> String {
+    match options.format {
+        ReleaseFormat::Markdown => release.get_fixed_contents(),
+        ReleaseFormat::ReleaseNotes => get_release_notes(release, options.strip_categories),
+    }
+}
+
+/// Returns the release notes for the given release, which contain the change types
+/// and their entries but no release header.
+fn get_release_notes(release: &Release, strip_categories: bool) -> String {
+    release
+        .change_types
+        .iter()
+        .map(|ct| {
+            let mut section = format!("{}\n\n", ct.fixed);
+
+            for entry in &ct.entries {
+                let line = match strip_categories && !entry.category.is_empty() {
+                    true => entry
+                        .fixed
+                        .replacen(format!("({}) ", entry.category).as_str(), "", 1),
+                    false => entry.fixed.clone(),
+                };
+
+                section.push_str(line.as_str());
+                section.push('\n');
+            }
+
+            section
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -5,29 +5,65 @@ use octocrab::models::pulls::PullRequest;
 use octocrab::params::repos::Reference::Branch;
 use octocrab::{self, Octocrab};
 use regex::{Regex, RegexBuilder};
-use std::process::Command;
+use std::{future::Future, process::Command, time::Duration};
 
 /// Holds the relevant information for a given PR.
-#[derive(Default)]
+#[derive(Debug, Default, PartialEq)]
 pub struct PRInfo {
     pub change_type: String,
     pub category: String,
+    /// The raw conventional commit type and scope from the PR title (e.g. `fix(cli)`).
+    pub commit_type: String,
     pub description: String,
-    pub number: u16,
+    pub number: u64,
 }
 
 /// Extracts the pull request information from the given
 /// instance.
 pub fn extract_pr_info(config: &Config, pr: &PullRequest) -> Result<PRInfo, GitHubError> {
+    let pr_title = pr.title.clone().unwrap_or("".to_string());
+    let mut pr_info = parse_pr_title(config, pr_title.as_str())?;
+
+    // NOTE: the labels are only considered if the title does not contain a valid change type
+    if pr_info.change_type.is_empty() {
+        if let Some(ct) = pr
+            .labels
+            .iter()
+            .flatten()
+            .find_map(|l| get_change_type_from_label(config, l.name.as_str()))
+        {
+            pr_info.change_type = ct;
+        }
+    }
+
+    Ok(PRInfo {
+        number: pr.number,
+        ..pr_info
+    })
+}
+
+/// Returns the change type, that is mapped to the given PR label in the configuration.
+fn get_change_type_from_label(config: &Config, label: &str) -> Option<String> {
+    let abbrev = config.label_change_type_map.get(label)?;
+
+    config
+        .change_types
+        .iter()
+        .find(|&(_, a)| a.eq(abbrev))
+        .map(|(name, _)| name.to_string())
+}
+
+/// Parses the change type, category and description from a PR title
+/// in the conventional commit format (e.g. `fix(cli): Fix bug.`).
+pub fn parse_pr_title(config: &Config, pr_title: &str) -> Result<PRInfo, GitHubError> {
     let mut change_type = String::new();
     let mut category = String::new();
+    let mut commit_type = String::new();
     let mut description = String::new();
 
-    let pr_title = pr.title.clone().unwrap_or("".to_string());
-
     if let Some(i) = RegexBuilder::new(r"^(?P<ct>\w+)?\s*(\((?P<cat>\w+)\))?[:\s]*(?P<desc>.+)$")
         .build()?
-        .captures(pr_title.as_str())
+        .captures(pr_title)
     {
         if let Some(ct) = i.name("ct") {
             if let Some((name, _)) = config
@@ -36,6 +72,12 @@ pub fn extract_pr_info(config: &Config, pr: &PullRequest) -> Result<PRInfo, GitH
                 .find(|&(_, abbrev)| abbrev.eq(ct.into()))
             {
                 change_type.clone_from(name);
+
+                // NOTE: the original commit type is only retained if it maps to a configured change type
+                commit_type = match i.name("cat") {
+                    Some(cat) => format!("{}({})", ct.as_str(), cat.as_str()),
+                    None => ct.as_str().to_string(),
+                };
             }
         };
 
@@ -49,16 +91,36 @@ pub fn extract_pr_info(config: &Config, pr: &PullRequest) -> Result<PRInfo, GitH
     };
 
     Ok(PRInfo {
-        number: pr
-            .number
-            .try_into()
-            .expect("failed to convert PR number to u16"),
         change_type,
         category,
+        commit_type,
         description,
+        number: 0,
     })
 }
 
+/// Returns whether network calls are disabled by setting the `CLU_OFFLINE`
+/// environment variable to `1` or `true`.
+pub fn is_offline() -> bool {
+    std::env::var("CLU_OFFLINE").is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+}
+
+/// Returns the information of the open pull request for the current branch.
+///
+/// NOTE: In offline mode, no request is made and the default information is returned.
+pub async fn get_pr_info(
+    config: &Config,
+    git_info: GitInfo,
+    offline: bool,
+) -> Result<PRInfo, GitHubError> {
+    if offline {
+        return Ok(PRInfo::default());
+    }
+
+    let pr = get_open_pr(config, git_info).await?;
+    extract_pr_info(config, &pr)
+}
+
 /// Returns an authenticated Octocrab instance if possible.
 pub fn get_authenticated_github_client() -> Result<Octocrab, GitHubError> {
     // NOTE: make sure to export the token and not only define using GITHUB_TOKEN=... because Rust executes
@@ -70,6 +132,29 @@ pub fn get_authenticated_github_client() -> Result<Octocrab, GitHubError> {
         .build()?)
 }
 
+/// Returns an authenticated Octocrab instance if the GitHub token is set
+/// and an unauthenticated one otherwise.
+///
+/// NOTE: Unauthenticated clients are sufficient for read-only requests
+/// on public repositories, but are subject to lower rate limits.
+pub fn get_github_client() -> Octocrab {
+    build_client(std::env::var("GITHUB_TOKEN").ok())
+        .map(|(client, _)| client)
+        .unwrap_or_default()
+}
+
+/// Builds a GitHub client, that is authenticated with the given token if it is set,
+/// and returns it together with whether it is authenticated.
+fn build_client(token: Option<String>) -> Result<(Octocrab, bool), GitHubError> {
+    match token.filter(|t| !t.is_empty()) {
+        Some(t) => Ok((
+            octocrab::OctocrabBuilder::new().personal_token(t).build()?,
+            true,
+        )),
+        None => Ok((Octocrab::default(), false)),
+    }
+}
+
 /// Checks if the given branch exists on the GitHub repository.
 pub async fn branch_exists_on_remote(client: &Octocrab, git_info: &GitInfo) -> bool {
     client
@@ -81,18 +166,24 @@ pub async fn branch_exists_on_remote(client: &Octocrab, git_info: &GitInfo) -> b
 
 /// Returns an option for an open PR from the current local branch in the configured target
 /// repository if it exists.
-pub async fn get_open_pr(git_info: GitInfo) -> Result<PullRequest, GitHubError> {
-    let octocrab = match get_authenticated_github_client() {
-        Ok(oc) => oc,
-        _ => octocrab::Octocrab::default(),
-    };
-
-    let pulls = octocrab
-        .pulls(git_info.owner, git_info.repo)
-        .list()
-        .send()
-        .await?
-        .items;
+pub async fn get_open_pr(config: &Config, git_info: GitInfo) -> Result<PullRequest, GitHubError> {
+    let octocrab = &get_github_client();
+    let gi = &git_info;
+
+    let pulls = retry_with_backoff(
+        config.max_github_attempts,
+        Duration::from_secs(1),
+        is_rate_limit_error,
+        || async move {
+            octocrab
+                .pulls(gi.owner.as_str(), gi.repo.as_str())
+                .list()
+                .send()
+                .await
+        },
+    )
+    .await?
+    .items;
     match pulls.iter().find(|pr| {
         pr.head.label.as_ref().is_some_and(|l| {
             let branch_parts: Vec<&str> = l.split(':').collect();
@@ -108,6 +199,141 @@ pub async fn get_open_pr(git_info: GitInfo) -> Result<PullRequest, GitHubError>
     }
 }
 
+/// Returns the login of the author of the given PR in the target repository.
+pub async fn get_pr_author(
+    config: &Config,
+    client: &Octocrab,
+    git_info: &GitInfo,
+    pr_number: u64,
+) -> Result<Option<String>, GitHubError> {
+    let pr = retry_with_backoff(
+        config.max_github_attempts,
+        Duration::from_secs(1),
+        is_rate_limit_error,
+        || async move {
+            client
+                .pulls(git_info.owner.as_str(), git_info.repo.as_str())
+                .get(pr_number)
+                .await
+        },
+    )
+    .await?;
+
+    Ok(pr.user.map(|u| u.login))
+}
+
+/// Returns the PR with the given number in the target repository
+/// or `None` if it does not exist.
+pub async fn get_pr_by_number(
+    config: &Config,
+    client: &Octocrab,
+    git_info: &GitInfo,
+    pr_number: u64,
+) -> Result<Option<PullRequest>, GitHubError> {
+    let res = retry_with_backoff(
+        config.max_github_attempts,
+        Duration::from_secs(1),
+        is_rate_limit_error,
+        || async move {
+            client
+                .pulls(git_info.owner.as_str(), git_info.repo.as_str())
+                .get(pr_number)
+                .await
+        },
+    )
+    .await;
+
+    match res {
+        Ok(pr) => Ok(Some(pr)),
+        Err(GitHubError::GitHub(e)) if is_not_found_error(&e) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the titles of the most recently merged PRs in the target repository.
+///
+/// NOTE: Only the most recently updated closed PRs are queried (up to 100).
+pub async fn get_merged_pr_titles(
+    config: &Config,
+    client: &Octocrab,
+    git_info: &GitInfo,
+) -> Result<Vec<String>, GitHubError> {
+    let pulls = retry_with_backoff(
+        config.max_github_attempts,
+        Duration::from_secs(1),
+        is_rate_limit_error,
+        || async move {
+            client
+                .pulls(git_info.owner.as_str(), git_info.repo.as_str())
+                .list()
+                .state(octocrab::params::State::Closed)
+                .sort(octocrab::params::pulls::Sort::Updated)
+                .direction(octocrab::params::Direction::Descending)
+                .per_page(100)
+                .send()
+                .await
+        },
+    )
+    .await?
+    .items;
+
+    Ok(pulls
+        .into_iter()
+        .filter(|pr| pr.merged_at.is_some())
+        .filter_map(|pr| pr.title)
+        .collect())
+}
+
+/// Executes the given call and retries it with an exponential backoff
+/// if the returned error is identified as a rate limit by the given predicate.
+///
+/// NOTE: If all attempts are rate limited, `GitHubError::RateLimited` is returned.
+pub async fn retry_with_backoff<T, E, F, Fut>(
+    max_attempts: u32,
+    base_delay: Duration,
+    is_rate_limited: impl Fn(&E) -> bool,
+    mut call: F,
+) -> Result<T, GitHubError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    GitHubError: From<E>,
+{
+    let mut attempt = 1;
+    loop {
+        match call().await {
+            Ok(res) => return Ok(res),
+            Err(e) if is_rate_limited(&e) => {
+                if attempt >= max_attempts {
+                    return Err(GitHubError::RateLimited(attempt));
+                }
+
+                tokio::time::sleep(base_delay * 2u32.pow(attempt - 1)).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Checks if the given error was caused by a (secondary) rate limit of the GitHub API.
+fn is_rate_limit_error(err: &octocrab::Error) -> bool {
+    match err {
+        octocrab::Error::GitHub { source, .. } => {
+            matches!(source.status_code.as_u16(), 403 | 429)
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the given error was caused by a resource not existing.
+fn is_not_found_error(err: &octocrab::Error) -> bool {
+    match err {
+        octocrab::Error::GitHub { source, .. } => source.status_code.as_u16() == 404,
+        _ => false,
+    }
+}
+
 /// Retrieves the name of the current branch if the working directory
 /// is a Git repository.
 fn get_current_local_branch() -> Result<String, GitHubError> {
@@ -121,12 +347,71 @@ fn get_current_local_branch() -> Result<String, GitHubError> {
     }
 }
 
+/// Returns the default branch of the repository, which is derived from the
+/// HEAD of the origin remote if possible and otherwise from the existence
+/// of a local `main` or `master` branch.
+///
+/// NOTE: This only uses the local Git information and does not query the remote.
+pub fn get_default_branch() -> Result<String, GitHubError> {
+    let output = Command::new("git")
+        .args(vec!["symbolic-ref", "refs/remotes/origin/HEAD"])
+        .output()?;
+
+    if output.status.success() {
+        let head = String::from_utf8(output.stdout)?;
+        if let Some(branch) = head.trim().strip_prefix("refs/remotes/origin/") {
+            return Ok(branch.to_string());
+        }
+    }
+
+    for branch in ["main", "master"] {
+        if Command::new("git")
+            .args(vec![
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                format!("refs/heads/{branch}").as_str(),
+            ])
+            .output()?
+            .status
+            .success()
+        {
+            return Ok(branch.to_string());
+        }
+    }
+
+    Err(GitHubError::DefaultBranch)
+}
+
+/// Returns the subject line of the most recent commit.
+pub fn get_last_commit_subject() -> Result<String, GitHubError> {
+    let output = Command::new("git")
+        .args(vec!["log", "-1", "--pretty=%s"])
+        .output()?;
+
+    match output.status.success() {
+        true => Ok(String::from_utf8(output.stdout)?.trim().to_string()),
+        false => Err(GitHubError::LastCommit),
+    }
+}
+
 /// Commits the current changes with the given commit message and pushes to the origin.
-pub fn commit_and_push(config: &Config, message: &str) -> Result<(), GitHubError> {
+pub fn commit_and_push(
+    config: &Config,
+    message: &str,
+    co_authors: &[String],
+    signoff: bool,
+) -> Result<(), GitHubError> {
     stage_changelog_changes(config)?;
 
     match Command::new("git")
-        .args(vec!["commit", "-a", "-m", message])
+        .args(["commit", "-a"])
+        .args(build_commit_args(
+            message,
+            co_authors,
+            &config.commit_trailers,
+            signoff,
+        ))
         .status()?
         .success()
     {
@@ -135,12 +420,23 @@ pub fn commit_and_push(config: &Config, message: &str) -> Result<(), GitHubError
     }
 }
 
-/// Commits the current changes with the given commit message and pushes to the origin.
-pub fn commit(config: &Config, message: &str) -> Result<(), GitHubError> {
+/// Commits the current changes with the given commit message.
+pub fn commit(
+    config: &Config,
+    message: &str,
+    co_authors: &[String],
+    signoff: bool,
+) -> Result<(), GitHubError> {
     stage_changelog_changes(config)?;
 
     if !Command::new("git")
-        .args(vec!["commit", "-m", message])
+        .arg("commit")
+        .args(build_commit_args(
+            message,
+            co_authors,
+            &config.commit_trailers,
+            signoff,
+        ))
         .status()?
         .success()
     {
@@ -150,6 +446,63 @@ pub fn commit(config: &Config, message: &str) -> Result<(), GitHubError> {
     Ok(())
 }
 
+/// Returns the commit message with a `Co-authored-by` trailer appended
+/// for each of the given co-authors.
+pub fn build_commit_message(message: &str, co_authors: &[String]) -> String {
+    if co_authors.is_empty() {
+        return message.to_string();
+    }
+
+    let trailers = co_authors
+        .iter()
+        .map(|ca| format!("Co-authored-by: {}", ca.trim()))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!("{}\n\n{}", message.trim_end(), trailers)
+}
+
+/// Returns the arguments for `git commit`, where each paragraph of the commit
+/// message is passed with a separate `-m` flag.
+///
+/// NOTE: The given trailers are added to the same paragraph as the co-author trailers,
+/// so that Git recognizes all of them as trailers. If signing off, Git adds
+/// the `Signed-off-by` trailer itself.
+pub fn build_commit_args(
+    message: &str,
+    co_authors: &[String],
+    trailers: &[String],
+    signoff: bool,
+) -> Vec<String> {
+    let mut args: Vec<String> = Vec::new();
+    if signoff {
+        args.push("--signoff".to_string());
+    }
+
+    let mut message = build_commit_message(message, co_authors);
+    if !trailers.is_empty() {
+        let separator = match co_authors.is_empty() {
+            true => "\n\n",
+            false => "\n",
+        };
+        let trailers = trailers
+            .iter()
+            .map(|t| t.trim())
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        message = format!("{}{}{}", message.trim_end(), separator, trailers);
+    }
+
+    message
+        .split("\n\n")
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .for_each(|p| args.extend(["-m".to_string(), p.to_string()]));
+
+    args
+}
+
 /// Adds the changelog to the staged changes in Git.
 fn stage_changelog_changes(config: &Config) -> Result<(), GitHubError> {
     if !Command::new("git")
@@ -185,7 +538,7 @@ pub fn push_to_origin(branch_name: &str) -> Result<(), GitHubError> {
 
 /// Checks if there is a origin repository defined and returns the name
 /// if that's the case.
-pub fn get_origin() -> Result<String, GitHubError> {
+pub fn get_origin(config: &Config) -> Result<String, GitHubError> {
     let output = Command::new("git")
         .args(vec!["remote", "get-url", "origin"])
         .output()?;
@@ -195,13 +548,19 @@ pub fn get_origin() -> Result<String, GitHubError> {
     };
 
     let origin = String::from_utf8(output.stdout)?;
-    match Regex::new(r"(https://github.com/[^.\s]+/[^.\s]+)(\.git)?")?.captures(origin.as_str()) {
+    parse_origin(config.get_forge_host(), origin.as_str())
+}
+
+/// Extracts the repository URL from the given origin URL for the given GitHub host.
+fn parse_origin(host: &str, origin: &str) -> Result<String, GitHubError> {
+    let pattern = format!(r"(https://{}/[^.\s]+/[^.\s]+)(\.git)?", regex::escape(host));
+    match Regex::new(pattern.as_str())?.captures(origin) {
         Some(o) => Ok(o
             .get(1)
             .expect("unexpected matching condition")
             .as_str()
             .to_string()),
-        None => Err(GitHubError::RegexMatch(origin)),
+        None => Err(GitHubError::RegexMatch(origin.to_string())),
     }
 }
 
@@ -216,7 +575,11 @@ pub struct GitInfo {
 /// Retrieves the Git information like the currently checked out branch and
 /// repository owner and name.
 pub fn get_git_info(config: &Config) -> Result<GitInfo, GitHubError> {
-    let captures = match Regex::new(r"github.com/(?P<owner>[\w-]+)/(?P<repo>[\w-]+)\.*")
+    let pattern = format!(
+        r"{}/(?P<owner>[\w-]+)/(?P<repo>[\w-]+)\.*",
+        regex::escape(config.get_forge_host())
+    );
+    let captures = match Regex::new(pattern.as_str())
         .expect("failed to build regular expression")
         .captures(config.target_repo.as_str())
     {
@@ -247,9 +610,338 @@ mod tests {
         assert_ne!(branch, "", "expected non-empty current branch")
     }
 
+    #[tokio::test]
+    async fn test_get_pr_info_offline() {
+        let config = load_test_config();
+        let git_info = GitInfo {
+            owner: "MalteHerrmann".to_string(),
+            repo: "changelog-utils".to_string(),
+            branch: "main".to_string(),
+        };
+
+        let pr_info = get_pr_info(&config, git_info, true)
+            .await
+            .expect("failed to get PR info in offline mode");
+        assert_eq!(pr_info, PRInfo::default());
+    }
+
+    // NOTE: building the clients requires a Tokio runtime
+    #[tokio::test]
+    async fn test_build_client() {
+        let (_, is_authenticated) = build_client(None).expect("failed to build client");
+        assert!(!is_authenticated);
+
+        let (_, is_authenticated) =
+            build_client(Some(String::new())).expect("failed to build client");
+        assert!(!is_authenticated);
+
+        let (_, is_authenticated) =
+            build_client(Some("test-token".into())).expect("failed to build client");
+        assert!(is_authenticated);
+    }
+
+    #[cfg(not(feature = "remote"))]
+    #[test]
+    fn test_default_branch() {
+        let branch = get_default_branch().expect("failed to get default branch");
+        assert_ne!(branch, "", "expected non-empty default branch")
+    }
+
+    #[cfg(not(feature = "remote"))]
+    #[test]
+    fn test_last_commit_subject() {
+        let subject = get_last_commit_subject().expect("failed to get last commit subject");
+        assert_ne!(subject, "", "expected non-empty commit subject")
+    }
+
+    fn load_test_config() -> Config {
+        crate::config::unpack_config(include_str!("testdata/example_config.json"))
+            .expect("failed to load example config")
+    }
+
+    #[test]
+    fn test_parse_pr_title() {
+        let pr_info = parse_pr_title(&load_test_config(), "fix(cli): handle empty input")
+            .expect("failed to parse PR title");
+        assert_eq!(pr_info.change_type, "Bug Fixes");
+        assert_eq!(pr_info.category, "cli");
+        assert_eq!(pr_info.commit_type, "fix(cli)");
+        assert_eq!(pr_info.description, "Handle empty input.");
+    }
+
+    #[test]
+    fn test_parse_pr_title_without_scope() {
+        let pr_info = parse_pr_title(&load_test_config(), "feat: add stats command")
+            .expect("failed to parse PR title");
+        assert_eq!(pr_info.change_type, "Features");
+        assert_eq!(pr_info.category, "");
+        assert_eq!(pr_info.commit_type, "feat");
+    }
+
+    fn get_pr_with_label(title: &str, label: &str) -> PullRequest {
+        let label_json = serde_json::json!({
+            "id": 1,
+            "node_id": "label",
+            "url": "https://api.github.com/repos/MalteHerrmann/changelog-utils/labels/1",
+            "name": label,
+            "color": "ffffff",
+            "default": false,
+        });
+        let branch_json = serde_json::json!({"ref": "main", "sha": "abc"});
+
+        serde_json::from_value(serde_json::json!({
+            "url": "https://api.github.com/repos/MalteHerrmann/changelog-utils/pulls/12",
+            "id": 1,
+            "number": 12,
+            "title": title,
+            "labels": [label_json],
+            "locked": false,
+            "maintainer_can_modify": false,
+            "head": branch_json,
+            "base": branch_json,
+        }))
+        .expect("failed to build pull request")
+    }
+
+    #[test]
+    fn test_extract_pr_info_from_label() {
+        let mut config = load_test_config();
+        config
+            .label_change_type_map
+            .insert("type: bug".into(), "fix".into());
+
+        let pr_info = extract_pr_info(&config, &get_pr_with_label("", "type: bug"))
+            .expect("failed to extract PR info");
+        assert_eq!(pr_info.change_type, "Bug Fixes");
+        assert_eq!(pr_info.number, 12);
+    }
+
+    #[test]
+    fn test_extract_pr_info_title_has_precedence_over_label() {
+        let mut config = load_test_config();
+        config
+            .label_change_type_map
+            .insert("type: bug".into(), "fix".into());
+
+        let pr_info = extract_pr_info(
+            &config,
+            &get_pr_with_label("feat(cli): add command", "type: bug"),
+        )
+        .expect("failed to extract PR info");
+        assert_eq!(pr_info.change_type, "Features");
+    }
+
+    #[test]
+    fn test_extract_pr_info_unmapped_label() {
+        let pr_info = extract_pr_info(&load_test_config(), &get_pr_with_label("", "type: bug"))
+            .expect("failed to extract PR info");
+        assert_eq!(pr_info.change_type, "");
+    }
+
+    #[test]
+    fn test_parse_pr_title_unknown_type() {
+        let pr_info = parse_pr_title(&load_test_config(), "chore(cli): bump deps")
+            .expect("failed to parse PR title");
+        assert_eq!(pr_info.change_type, "");
+        assert_eq!(pr_info.commit_type, "");
+    }
+
+    #[test]
+    fn test_build_commit_args() {
+        assert_eq!(
+            build_commit_args("add changelog entry", &[], &[], false),
+            ["-m", "add changelog entry"]
+        );
+    }
+
+    #[test]
+    fn test_build_commit_args_with_signoff() {
+        assert_eq!(
+            build_commit_args("add changelog entry", &[], &[], true),
+            ["--signoff", "-m", "add changelog entry"]
+        );
+    }
+
+    #[test]
+    fn test_build_commit_args_with_body_and_trailers() {
+        assert_eq!(
+            build_commit_args(
+                "add changelog entry\n\nThe entry describes the fix.",
+                &["Jane Doe <jane@example.com>".to_string()],
+                &["Signed-off-by: John Doe <john@example.com>".to_string()],
+                false
+            ),
+            [
+                "-m",
+                "add changelog entry",
+                "-m",
+                "The entry describes the fix.",
+                "-m",
+                concat!(
+                    "Co-authored-by: Jane Doe <jane@example.com>\n",
+                    "Signed-off-by: John Doe <john@example.com>"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_commit_args_with_trailers_only() {
+        assert_eq!(
+            build_commit_args(
+                "add changelog entry",
+                &[],
+                &["Signed-off-by: John Doe <john@example.com>".to_string()],
+                false
+            ),
+            [
+                "-m",
+                "add changelog entry",
+                "-m",
+                "Signed-off-by: John Doe <john@example.com>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_commit_message_without_co_authors() {
+        assert_eq!(
+            build_commit_message("add changelog entry", &[]),
+            "add changelog entry"
+        );
+    }
+
+    #[test]
+    fn test_build_commit_message_with_co_author() {
+        assert_eq!(
+            build_commit_message(
+                "add changelog entry",
+                &["Jane Doe <jane@example.com>".to_string()]
+            ),
+            "add changelog entry\n\nCo-authored-by: Jane Doe <jane@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_build_commit_message_with_multiple_co_authors() {
+        assert_eq!(
+            build_commit_message(
+                "add changelog entry\n",
+                &[
+                    "Jane Doe <jane@example.com>".to_string(),
+                    "John Doe <john@example.com>".to_string(),
+                ]
+            ),
+            concat!(
+                "add changelog entry\n\n",
+                "Co-authored-by: Jane Doe <jane@example.com>\n",
+                "Co-authored-by: John Doe <john@example.com>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_origin_enterprise() {
+        let origin = parse_origin(
+            "github.mycorp.com",
+            "https://github.mycorp.com/MalteHerrmann/changelog-utils.git\n",
+        )
+        .expect("failed to parse origin");
+        assert_eq!(
+            origin,
+            "https://github.mycorp.com/MalteHerrmann/changelog-utils"
+        );
+    }
+
+    #[test]
+    fn test_parse_origin_gitlab() {
+        let config = Config {
+            forge: crate::config::ForgeKind::GitLab,
+            ..Config::default()
+        };
+        let origin = parse_origin(
+            config.get_forge_host(),
+            "https://gitlab.com/MalteHerrmann/changelog-utils.git\n",
+        )
+        .expect("failed to parse origin");
+        assert_eq!(origin, "https://gitlab.com/MalteHerrmann/changelog-utils");
+    }
+
+    #[test]
+    fn test_parse_origin_wrong_host() {
+        assert!(parse_origin(
+            "github.mycorp.com",
+            "https://github.com/MalteHerrmann/changelog-utils.git\n",
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_succeeds_after_failures() {
+        let mut calls = 0;
+        let res = retry_with_backoff(
+            3,
+            Duration::ZERO,
+            |e| matches!(e, GitHubError::NoOpenPR),
+            || {
+                calls += 1;
+                let current = calls;
+                async move {
+                    match current {
+                        1 | 2 => Err(GitHubError::NoOpenPR),
+                        _ => Ok(current),
+                    }
+                }
+            },
+        )
+        .await
+        .expect("expected call to succeed");
+
+        assert_eq!(res, 3);
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_exhausted() {
+        let mut calls = 0;
+        let err = retry_with_backoff(
+            2,
+            Duration::ZERO,
+            |e| matches!(e, GitHubError::NoOpenPR),
+            || {
+                calls += 1;
+                async { Err::<(), GitHubError>(GitHubError::NoOpenPR) }
+            },
+        )
+        .await
+        .expect_err("expected call to fail");
+
+        assert!(matches!(err, GitHubError::RateLimited(2)));
+        assert_eq!(calls, 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_other_error() {
+        let mut calls = 0;
+        let err = retry_with_backoff(
+            3,
+            Duration::ZERO,
+            |e| matches!(e, GitHubError::NoOpenPR),
+            || {
+                calls += 1;
+                async { Err::<(), GitHubError>(GitHubError::CurrentBranch) }
+            },
+        )
+        .await
+        .expect_err("expected call to fail");
+
+        assert!(matches!(err, GitHubError::CurrentBranch));
+        assert_eq!(calls, 1);
+    }
+
     #[test]
     fn test_get_origin() {
-        let origin = get_origin().expect("failed to get origin");
+        let origin = get_origin(&load_test_config()).expect("failed to get origin");
         assert_eq!(
             origin, "https://github.com/MalteHerrmann/changelog-utils",
             "expected different origin"
@@ -2,25 +2,36 @@ use crate::{
     changelog::get_settings_from_existing_changelog, config::Config, errors::InitError,
     github::get_origin,
 };
-use std::{fs, path::PathBuf};
+use clap::ValueEnum;
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+/// Represents the available presets for the initial configuration.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Preset {
+    /// Uses the section names from keepachangelog.com as change types.
+    #[value(name = "keepachangelog")]
+    KeepAChangelog,
+}
 
 /// Runs the logic to initialize the changelog utilities
 /// in the current working directory.
-pub fn run() -> Result<(), InitError> {
-    init_in_folder(std::env::current_dir()?)
+pub fn run(preset: Option<Preset>) -> Result<(), InitError> {
+    init_in_folder(std::env::current_dir()?, preset)
 }
 
 /// Runs the logic to initialize the changelog utilities in
 /// the given directory.
-pub fn init_in_folder(target: PathBuf) -> Result<(), InitError> {
+pub fn init_in_folder(target: PathBuf, preset: Option<Preset>) -> Result<(), InitError> {
     let config_path = target.join(".clconfig.json");
-    if std::fs::symlink_metadata(&config_path).is_ok() {
+    if std::fs::symlink_metadata(&config_path).is_ok()
+        || std::fs::symlink_metadata(target.join(".clconfig.toml")).is_ok()
+    {
         return Err(InitError::ConfigAlreadyFound);
     };
 
-    let mut config = Config::default();
+    let mut config = create_default_config(None);
 
-    if let Ok(origin) = get_origin() {
+    if let Ok(origin) = get_origin(&config) {
         config.target_repo.clone_from(&origin);
     };
 
@@ -39,6 +50,12 @@ pub fn init_in_folder(target: PathBuf) -> Result<(), InitError> {
         }
     }
 
+    // NOTE: an explicitly selected preset takes precedence over the settings
+    // derived from an existing changelog.
+    if preset.is_some() {
+        config.change_types = create_default_config(preset).change_types;
+    }
+
     println!(
         "created new configuration at {}:\n{}",
         &config_path.as_os_str().to_string_lossy(),
@@ -47,6 +64,25 @@ pub fn init_in_folder(target: PathBuf) -> Result<(), InitError> {
     Ok(config.export(config_path.as_path())?)
 }
 
+/// Creates the default configuration for the given preset.
+pub fn create_default_config(preset: Option<Preset>) -> Config {
+    let mut config = Config::default();
+
+    if let Some(Preset::KeepAChangelog) = preset {
+        let mut change_types: BTreeMap<String, String> = BTreeMap::new();
+        change_types.insert("Added".into(), "add".into());
+        change_types.insert("Changed".into(), "change".into());
+        change_types.insert("Deprecated".into(), "deprecate".into());
+        change_types.insert("Fixed".into(), "fix".into());
+        change_types.insert("Removed".into(), "remove".into());
+        change_types.insert("Security".into(), "security".into());
+
+        config.change_types = change_types;
+    }
+
+    config
+}
+
 /// Creates an empty skeleton for a changelog.
 pub fn create_empty_changelog() -> String {
     [
@@ -0,0 +1,140 @@
+use crate::{
+    cli::InitCIArgs,
+    config::{self, Config, ForgeKind},
+    errors::InitError,
+    github::get_origin,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Represents the supported CI providers to create a workflow for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CIProvider {
+    GitHub,
+    GitLab,
+}
+
+/// Runs the logic to create a CI workflow, that lints the changelog,
+/// in the current working directory.
+pub fn run(args: InitCIArgs) -> Result<(), InitError> {
+    let provider = match args.gitlab {
+        true => CIProvider::GitLab,
+        false => CIProvider::GitHub,
+    };
+
+    // NOTE: the workflow can be created before the configuration exists,
+    // in which case the default host of the CI provider's forge is used to parse the origin.
+    let config = match config::get_config_path(Path::new("./")).exists() {
+        true => config::load()?,
+        false => Config {
+            forge: match provider {
+                CIProvider::GitHub => ForgeKind::GitHub,
+                CIProvider::GitLab => ForgeKind::GitLab,
+            },
+            ..Config::default()
+        },
+    };
+
+    let target_repo = get_origin(&config).ok();
+    let path = init_ci_in_folder(
+        std::env::current_dir()?.as_path(),
+        provider,
+        target_repo.as_deref(),
+    )?;
+
+    println!("created CI workflow at {}", path.to_string_lossy());
+
+    Ok(())
+}
+
+/// Writes the CI workflow for the given provider into the given directory
+/// and returns the path of the created file.
+///
+/// NOTE: Existing workflow files are not overwritten.
+pub fn init_ci_in_folder(
+    target: &Path,
+    provider: CIProvider,
+    target_repo: Option<&str>,
+) -> Result<PathBuf, InitError> {
+    let workflow_path = match provider {
+        CIProvider::GitHub => target.join(".github/workflows/changelog.yml"),
+        CIProvider::GitLab => target.join(".gitlab-ci.yml"),
+    };
+
+    if workflow_path.exists() {
+        return Err(InitError::WorkflowAlreadyFound(
+            workflow_path.to_string_lossy().to_string(),
+        ));
+    }
+
+    if let Some(parent) = workflow_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let workflow = match provider {
+        CIProvider::GitHub => create_github_workflow(target_repo),
+        CIProvider::GitLab => create_gitlab_workflow(target_repo),
+    };
+    fs::write(&workflow_path, workflow)?;
+
+    Ok(workflow_path)
+}
+
+/// Creates a GitHub Actions workflow, that lints the changelog on pull requests.
+pub fn create_github_workflow(target_repo: Option<&str>) -> String {
+    [
+        get_header(target_repo).as_str(),
+        "name: Changelog",
+        "",
+        "on:",
+        "  pull_request:",
+        "",
+        "jobs:",
+        "  lint-changelog:",
+        "    runs-on: ubuntu-latest",
+        "    steps:",
+        "      - name: Check out the repository",
+        "        uses: actions/checkout@v4",
+        "      - name: Install Rust",
+        "        uses: dtolnay/rust-toolchain@stable",
+        "      - name: Install clu",
+        "        run: cargo install --git https://github.com/MalteHerrmann/changelog-utils",
+        "      - name: Lint changelog",
+        "        run: clu lint",
+        "",
+    ]
+    .join("\n")
+}
+
+/// Creates a GitLab CI configuration, that lints the changelog in merge requests.
+pub fn create_gitlab_workflow(target_repo: Option<&str>) -> String {
+    [
+        get_header(target_repo).as_str(),
+        "lint-changelog:",
+        "  image: rust:latest",
+        "  rules:",
+        "    - if: $CI_PIPELINE_SOURCE == \"merge_request_event\"",
+        "  script:",
+        "    - cargo install --git https://github.com/MalteHerrmann/changelog-utils",
+        "    - clu lint",
+        "",
+    ]
+    .join("\n")
+}
+
+/// Returns the comment header for the generated CI workflows.
+fn get_header(target_repo: Option<&str>) -> String {
+    let mut header = vec![
+        "# This workflow was created using the `clu` binary".to_string(),
+        "# (https://github.com/MalteHerrmann/changelog-utils).".to_string(),
+    ];
+
+    if let Some(repo) = target_repo {
+        header.push(format!("# Target repository: {repo}"));
+    }
+
+    header.push("".to_string());
+    header.join("\n")
+}
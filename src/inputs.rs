@@ -1,5 +1,5 @@
 use crate::{config::Config, errors::InputError, release_type::ReleaseType};
-use inquire::{Editor, Select, Text};
+use inquire::{Confirm, Editor, Select, Text};
 use octocrab::{models::repos::Branch, Page};
 
 pub fn get_change_type(config: &Config, start: usize) -> Result<String, InputError> {
@@ -14,11 +14,11 @@ pub fn get_change_type(config: &Config, start: usize) -> Result<String, InputErr
     )
 }
 
-pub fn get_pr_number(default_value: u16) -> Result<u16, InputError> {
+pub fn get_pr_number(default_value: u64) -> Result<u64, InputError> {
     Ok(Text::new("Please provide the PR number:")
         .with_initial_value(format!("{}", &default_value).as_str())
         .prompt()?
-        .parse::<u16>()?)
+        .parse::<u64>()?)
 }
 
 pub fn get_category(config: &Config, default_idx: usize) -> Result<String, InputError> {
@@ -31,11 +31,9 @@ pub fn get_category(config: &Config, default_idx: usize) -> Result<String, Input
 }
 
 pub fn get_commit_message(config: &Config) -> Result<String, InputError> {
-    Ok(
-        Text::new("Please provide the commit message:\n")
-            .with_initial_value(config.commit_message.as_str())
-            .prompt()?
-    )
+    Ok(Text::new("Please provide the commit message:\n")
+        .with_initial_value(config.commit_message.as_str())
+        .prompt()?)
 }
 
 pub fn get_description(default_value: &str) -> Result<String, InputError> {
@@ -46,6 +44,28 @@ pub fn get_description(default_value: &str) -> Result<String, InputError> {
     )
 }
 
+pub fn get_entry_to_keep(pr_number: u64, lines: &[String]) -> Result<usize, InputError> {
+    Ok(Select::new(
+        format!("Select the entry to keep for PR #{pr_number}:").as_str(),
+        lines.to_vec(),
+    )
+    .raw_prompt()?
+    .index)
+}
+
+pub fn get_permission_to_fix(line: &str, fixed: &str) -> Result<bool, InputError> {
+    match Select::new(
+        format!("Apply fix?\n- {}\n+ {}\n", line, fixed).as_str(),
+        vec!["yes", "no"],
+    )
+    .prompt()?
+    {
+        "yes" => Ok(true),
+        "no" => Ok(false),
+        &_ => Err(InputError::InvalidSelection),
+    }
+}
+
 pub fn get_permission_to_push(branch: &str) -> Result<bool, InputError> {
     match Select::new(
         format!(
@@ -63,6 +83,33 @@ pub fn get_permission_to_push(branch: &str) -> Result<bool, InputError> {
     }
 }
 
+pub fn get_permission_to_release(version: &str) -> Result<bool, InputError> {
+    match Select::new(
+        format!("Create release {}?", version).as_str(),
+        vec!["yes", "no"],
+    )
+    .prompt()?
+    {
+        "yes" => Ok(true),
+        "no" => Ok(false),
+        &_ => Err(InputError::InvalidSelection),
+    }
+}
+
+pub fn get_permission_to_create_draft() -> Result<bool, InputError> {
+    Ok(Confirm::new("Create the pull request as a draft?")
+        .with_default(false)
+        .prompt()?)
+}
+
+pub fn get_permission_to_add_category(category: &str, count: usize) -> Result<bool, InputError> {
+    Ok(Confirm::new(
+        format!("Add category '{category}' (used in {count} merged pull requests)?").as_str(),
+    )
+    .with_default(true)
+    .prompt()?)
+}
+
 pub fn get_pr_description() -> Result<String, InputError> {
     Ok(Editor::new(
         "Please provide the Pull Request body with a description of the made changes.\n",
@@ -85,13 +132,16 @@ pub fn get_release_type() -> Result<ReleaseType, InputError> {
     Err(InputError::InvalidSelection)
 }
 
-pub fn get_target_branch(branches_page: Page<Branch>) -> Result<String, InputError> {
+pub fn get_target_branch(
+    branches_page: Page<Branch>,
+    default_branch: &str,
+) -> Result<String, InputError> {
     let mut branches = Vec::new();
     let mut start_idx: usize = 0;
 
     branches_page.into_iter().enumerate().for_each(|(idx, b)| {
         branches.push(b.name.clone());
-        if b.name.eq("main") {
+        if b.name.eq(default_branch) {
             start_idx = idx;
         }
     });
@@ -0,0 +1,62 @@
+use crate::{
+    config,
+    errors::LearnCategoriesError,
+    github::{get_git_info, get_github_client, get_merged_pr_titles},
+    inputs,
+};
+use regex::Regex;
+use std::{collections::BTreeMap, path::Path};
+
+/// Runs the logic to propose the categories of recently merged PRs, that are not
+/// configured yet, and adds the confirmed ones to the configuration.
+pub async fn run() -> Result<(), LearnCategoriesError> {
+    let config = config::load()?;
+    let git_info = get_git_info(&config)?;
+    let titles = get_merged_pr_titles(&config, &get_github_client(), &git_info).await?;
+
+    let new_categories = get_new_categories(&config, &titles);
+    if new_categories.is_empty() {
+        println!("no new categories found in merged pull requests");
+        return Ok(());
+    }
+
+    // NOTE: the categories are added to the configuration file itself
+    // without merging the parent configurations it extends.
+    let config_path = config::get_config_path(Path::new("./"));
+    let mut configuration = config::read_partial_from_path(&config_path)?;
+    let categories = configuration.categories.get_or_insert_with(Vec::new);
+
+    let mut n_added = 0;
+    for (category, count) in new_categories {
+        if inputs::get_permission_to_add_category(category.as_str(), count)? {
+            config::add_category(categories, category)?;
+            n_added += 1;
+        }
+    }
+
+    if n_added > 0 {
+        configuration.export(&config_path)?;
+    }
+    println!("added {n_added} categories to the configuration");
+
+    Ok(())
+}
+
+/// Returns the categories used in the given PR titles, that are not configured yet,
+/// together with the number of PRs they are used in.
+///
+/// NOTE: The categories are expected in the conventional commit format, e.g. `fix(cli): ...`.
+pub fn get_new_categories(config: &config::Config, titles: &[String]) -> BTreeMap<String, usize> {
+    let category_regex =
+        Regex::new(r"^\s*\w+\((?P<cat>[a-zA-Z0-9\-]+)\)!?:").expect("invalid regex pattern");
+
+    let mut new_categories: BTreeMap<String, usize> = BTreeMap::new();
+    titles
+        .iter()
+        .filter_map(|t| category_regex.captures(t))
+        .map(|c| c["cat"].to_lowercase())
+        .filter(|c| !config.categories.contains(c))
+        .for_each(|c| *new_categories.entry(c).or_default() += 1);
+
+    new_categories
+}
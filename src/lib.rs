@@ -1,18 +1,33 @@
 pub mod add;
+pub mod baseline;
 mod change_type;
 pub mod changelog;
+pub mod check;
 pub mod cli;
 pub mod cli_config;
 pub mod config;
+pub mod config_schema;
 pub mod create_pr;
+pub mod dedupe;
 mod entry;
 pub mod errors;
 mod escapes;
+pub mod fix;
+pub mod get;
 pub mod github;
 pub mod init;
+pub mod init_ci;
 mod inputs;
+pub mod learn_categories;
 pub mod lint;
+pub mod move_entry;
+mod pager;
+pub mod path;
+pub mod problem;
 mod release;
 pub mod release_cli;
 mod release_type;
+pub mod remove;
+pub mod stats;
+pub mod verify_links;
 mod version;
@@ -1,16 +1,45 @@
 use crate::{
-    changelog,
+    baseline, changelog,
     changelog::{parse_changelog, Changelog},
+    cli::LintArgs,
     config,
     errors::LintError,
+    problem::{Problem, Severity},
+    version,
 };
-use std::path::Path;
+use regex::Regex;
+use std::{collections::BTreeMap, path::Path};
 
 /// Runs the main logic for the linter, by searching for the changelog file in the
 /// current directory and then executing the linting on the found file.
-pub fn run(fix: bool) -> Result<(), LintError> {
+pub fn run(fix: bool, args: LintArgs) -> Result<(), LintError> {
     let changelog = changelog::load(config::load()?)?;
-    match changelog.problems.is_empty() {
+
+    if let Some(path) = args.write_baseline {
+        baseline::Baseline::new(&changelog.problems).write(&path)?;
+        println!(
+            "wrote {} problems to baseline {}",
+            changelog.problems.len(),
+            path.to_string_lossy()
+        );
+        return Ok(());
+    }
+
+    // NOTE: known problems from the baseline are only ignored when linting, not when fixing
+    let mut problems = match (fix, &args.baseline) {
+        (false, Some(path)) => baseline::load(path)?.filter_new_problems(&changelog.problems),
+        _ => changelog.problems.clone(),
+    };
+    if !fix && (args.from.is_some() || args.to.is_some()) {
+        problems = filter_problems_by_release_range(
+            &changelog,
+            &problems,
+            args.from.as_deref(),
+            args.to.as_deref(),
+        )?;
+    }
+
+    match problems.is_empty() {
         true => {
             println!("changelog has no problems");
             Ok(())
@@ -18,12 +47,24 @@ pub fn run(fix: bool) -> Result<(), LintError> {
         false => match fix {
             false => {
                 println!("found problems in changelog:");
-                for problem in changelog.problems {
-                    println!("{}", problem);
+                for line in get_problem_output(&problems, args.max_problems, args.group) {
+                    println!("{}", line);
+                }
+
+                match has_failing_problems(&problems, args.fail_on) {
+                    true => Err(LintError::ProblemsInChangelog),
+                    false => Ok(()),
                 }
-                Err(LintError::ProblemsInChangelog)
             }
             true => {
+                if changelog.has_conflict_markers {
+                    for line in get_problem_output(&problems, args.max_problems, args.group) {
+                        println!("{}", line);
+                    }
+
+                    return Err(LintError::ConflictMarkers);
+                }
+
                 changelog.write(changelog.path.as_path())?;
                 println!(
                     "automated fixes were applied to {}",
@@ -35,7 +76,131 @@ pub fn run(fix: bool) -> Result<(), LintError> {
     }
 }
 
+/// Checks if any of the given problems is at least as severe as the given threshold.
+pub fn has_failing_problems(problems: &[Problem], fail_on: Severity) -> bool {
+    problems.iter().any(|p| p.severity >= fail_on)
+}
+
 /// Executes the linter logic.
 pub fn lint(config: config::Config, changelog_path: &Path) -> Result<Changelog, LintError> {
     Ok(parse_changelog(config, changelog_path)?)
 }
+
+/// Returns the problems, that occur in releases within the given version range (inclusive).
+///
+/// NOTE: The unreleased section is only included if no upper bound is given.
+/// Problems without a line number are always returned.
+pub fn filter_problems_by_release_range<T: AsRef<str> + Clone>(
+    changelog: &Changelog,
+    problems: &[T],
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<Vec<T>, LintError> {
+    let from = from.map(version::parse).transpose()?;
+    let to = to.map(version::parse).transpose()?;
+
+    let mut in_range: Vec<(usize, bool)> = Vec::new();
+    for release in &changelog.releases {
+        let included = match release.is_unreleased() {
+            true => to.is_none(),
+            // NOTE: releases with unparsable versions are skipped, because they cannot
+            // be placed in the range and are already reported by the linter.
+            false => match version::parse(release.version.as_str()) {
+                Ok(v) => {
+                    from.as_ref().map_or(true, |f| !f.gt(&v))
+                        && to.as_ref().map_or(true, |t| !v.gt(t))
+                }
+                Err(_) => false,
+            },
+        };
+        in_range.push((release.line_number, included));
+    }
+
+    let line_regex = Regex::new(r"^.+?:(?P<line>\d+)(:\d+)?: ").expect("invalid regex");
+
+    Ok(problems
+        .iter()
+        .filter(|p| match line_regex.captures(p.as_ref()) {
+            Some(c) => {
+                let line: usize = c["line"].parse().unwrap_or_default();
+                in_range
+                    .iter()
+                    .rev()
+                    .find(|(start, _)| *start <= line)
+                    .is_some_and(|(_, included)| *included)
+            }
+            None => true,
+        })
+        .cloned()
+        .collect())
+}
+
+/// Returns the lines to print for the given problems.
+///
+/// If a maximum number of problems is given, only the first problems are
+/// returned and a summary of the remaining number is appended.
+/// Optionally, the problems are grouped by the file they occur in.
+pub fn get_problem_output<T: AsRef<str>>(
+    problems: &[T],
+    max_problems: Option<usize>,
+    group: bool,
+) -> Vec<String> {
+    let max = max_problems.unwrap_or(problems.len());
+    let shown: Vec<String> = problems
+        .iter()
+        .take(max)
+        .map(|p| p.as_ref().to_string())
+        .collect();
+
+    let mut output = match group {
+        true => group_problems(&shown),
+        false => shown,
+    };
+
+    if problems.len() > max {
+        output.push(format!("... and {} more", problems.len() - max));
+    }
+
+    output
+}
+
+/// Groups the given problems by the contained file path, sorted by path and line number.
+///
+/// Each file is printed as a header with the corresponding problems indented below.
+/// Problems without a recognized `path:line: ` or `path:line:column: ` prefix
+/// are collected at the end.
+pub fn group_problems(problems: &[String]) -> Vec<String> {
+    let problem_regex =
+        Regex::new(r"^(?P<path>.+?):(?P<line>\d+)(?P<column>:\d+)?: (?P<problem>.*)$")
+            .expect("invalid regex");
+
+    let mut grouped: BTreeMap<String, Vec<(usize, String)>> = BTreeMap::new();
+    let mut ungrouped: Vec<String> = Vec::new();
+
+    for problem in problems {
+        match problem_regex.captures(problem) {
+            Some(c) => grouped.entry(c["path"].to_string()).or_default().push((
+                c["line"].parse().unwrap_or_default(),
+                format!(
+                    "{}: {}",
+                    c.name("column").map_or("", |m| m.as_str()),
+                    &c["problem"]
+                ),
+            )),
+            None => ungrouped.push(problem.to_string()),
+        }
+    }
+
+    let mut output: Vec<String> = Vec::new();
+    for (path, mut file_problems) in grouped {
+        file_problems.sort_by_key(|(line, _)| *line);
+
+        output.push(format!("{path}:"));
+        file_problems
+            .into_iter()
+            .for_each(|(line, p)| output.push(format!("  {line}{p}")));
+    }
+
+    output.append(&mut ungrouped);
+    output
+}
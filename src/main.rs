@@ -3,20 +3,31 @@ Main file to run the changelog utils application.
 */
 use clap::Parser;
 use clu::{
-    add, cli::ChangelogCLI, cli_config, create_pr, errors::CLIError, init, lint, release_cli,
+    add, check, cli::ChangelogCLI, cli_config, create_pr, dedupe, errors::CLIError, fix, get, init,
+    init_ci, learn_categories, lint, move_entry, path, release_cli, remove, stats, verify_links,
 };
 
 #[tokio::main]
 async fn main() -> Result<(), CLIError> {
     match ChangelogCLI::parse() {
-        ChangelogCLI::Add(add_args) => Ok(add::run(add_args.yes).await?),
-        ChangelogCLI::CreatePR => Ok(create_pr::run().await?),
-        ChangelogCLI::Fix => Ok(lint::run(true)?),
-        ChangelogCLI::Lint => Ok(lint::run(false)?),
-        ChangelogCLI::Init => Ok(init::run()?),
+        ChangelogCLI::Add(add_args) => Ok(add::run(add_args).await?),
+        ChangelogCLI::Check => Ok(check::run()?),
+        ChangelogCLI::CreatePR(args) => Ok(create_pr::run(args).await?),
+        ChangelogCLI::Dedupe(args) => Ok(dedupe::run(args)?),
+        ChangelogCLI::Fix(args) => Ok(fix::run(args)?),
+        ChangelogCLI::Get(args) => Ok(get::run(args)?),
+        ChangelogCLI::Lint(args) => Ok(lint::run(false, args)?),
+        ChangelogCLI::Init(args) => Ok(init::run(args.preset)?),
+        ChangelogCLI::InitCI(args) => Ok(init_ci::run(args)?),
         ChangelogCLI::Config(config_subcommand) => {
             Ok(cli_config::adjust_config(config_subcommand)?)
         }
-        ChangelogCLI::Release(args) => Ok(release_cli::run(args.version)?),
+        ChangelogCLI::LearnCategories => Ok(learn_categories::run().await?),
+        ChangelogCLI::Move(args) => Ok(move_entry::run(args)?),
+        ChangelogCLI::Path(args) => Ok(path::run(args)?),
+        ChangelogCLI::Release(args) => Ok(release_cli::run(args)?),
+        ChangelogCLI::Remove(args) => Ok(remove::run(args)?),
+        ChangelogCLI::VerifyLinks(args) => Ok(verify_links::run(args).await?),
+        ChangelogCLI::Stats(args) => Ok(stats::run(args).await?),
     }
 }
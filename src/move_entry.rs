@@ -0,0 +1,38 @@
+use crate::{add, changelog, cli::MoveArgs, config, errors::MoveError, remove};
+
+// Runs the logic to move the entry with the given PR number to another
+// change type in the unreleased section of the changelog.
+pub fn run(args: MoveArgs) -> Result<(), MoveError> {
+    let config = config::load()?;
+    let mut changelog = changelog::load(config.clone())?;
+
+    move_entry(
+        &config,
+        &mut changelog,
+        args.pr_number,
+        args.change_type.as_str(),
+    )?;
+
+    Ok(changelog.write(&changelog.path)?)
+}
+
+/// Moves the entry with the given PR number in the unreleased section
+/// of the changelog into the given change type.
+///
+/// NOTE: The target change type section is created if it does not exist yet,
+/// while emptied sections are removed.
+pub fn move_entry(
+    config: &config::Config,
+    changelog: &mut changelog::Changelog,
+    pr: u64,
+    change_type: &str,
+) -> Result<(), MoveError> {
+    if !config.change_types.contains_key(change_type) {
+        return Err(MoveError::InvalidChangeType(change_type.to_string()));
+    }
+
+    let entry = remove::remove_entry(changelog, pr)?;
+    add::insert_entry(changelog, change_type, entry);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::{
+    env,
+    io::{IsTerminal, Write},
+    process::{Command, Stdio},
+};
+
+/// The pager command, that is used if `$PAGER` is not set.
+const DEFAULT_PAGER: &str = "less -FRX";
+
+/// Prints the given output and pipes it through the user's pager if it does
+/// not fit into the terminal.
+///
+/// NOTE: If the pager cannot be started, the output is printed directly.
+pub fn print(output: &str, no_pager: bool) {
+    let height = crossterm::terminal::size().ok().map(|(_, h)| h);
+    if !should_page(
+        std::io::stdout().is_terminal(),
+        no_pager,
+        output.lines().count(),
+        height,
+    ) {
+        return println!("{}", output.trim_end());
+    }
+
+    if page(output).is_err() {
+        println!("{}", output.trim_end());
+    }
+}
+
+/// Returns whether the output should be shown in a pager, which is only the case
+/// for output to a terminal, that exceeds the terminal height.
+pub fn should_page(is_tty: bool, no_pager: bool, n_lines: usize, height: Option<u16>) -> bool {
+    if no_pager || !is_tty {
+        return false;
+    }
+
+    height.is_some_and(|h| n_lines > usize::from(h))
+}
+
+/// Pipes the given output through the configured pager.
+fn page(output: &str) -> std::io::Result<()> {
+    let pager = env::var("PAGER").unwrap_or(DEFAULT_PAGER.to_string());
+    let mut parts = pager.split_whitespace();
+    let program = parts.next().unwrap_or("less");
+
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // NOTE: errors are ignored here, because the user can quit the pager early
+        let _ = stdin.write_all(output.as_bytes());
+    }
+
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod pager_tests {
+    use super::*;
+
+    #[test]
+    fn test_page_long_output_in_terminal() {
+        assert!(should_page(true, false, 50, Some(24)));
+    }
+
+    #[test]
+    fn test_no_page_short_output() {
+        assert!(!should_page(true, false, 10, Some(24)));
+    }
+
+    #[test]
+    fn test_no_page_without_terminal() {
+        assert!(!should_page(false, false, 50, Some(24)));
+    }
+
+    #[test]
+    fn test_no_page_if_disabled() {
+        assert!(!should_page(true, true, 50, Some(24)));
+    }
+
+    #[test]
+    fn test_no_page_unknown_height() {
+        assert!(!should_page(true, false, 50, None));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{changelog, cli::PathArgs, config, errors::PathError};
+use std::path::{Path, PathBuf};
+
+/// Prints the absolute path of the changelog or the configuration
+/// in the current working directory.
+pub fn run(args: PathArgs) -> Result<(), PathError> {
+    let path = get_path(&std::env::current_dir()?, args.config)?;
+    println!("{}", path.to_string_lossy());
+
+    Ok(())
+}
+
+/// Returns the absolute path of the changelog or the configuration file
+/// in the given directory.
+///
+/// NOTE: If a configuration exists, the configured changelog path is used.
+pub fn get_path(dir: &Path, config: bool) -> Result<PathBuf, PathError> {
+    let config_path = config::get_config_path(dir);
+    let path = match config {
+        true => {
+            if !config_path.exists() {
+                return Err(PathError::NoConfigFound);
+            }
+
+            config_path
+        }
+        false => match config_path.exists() {
+            true => changelog::find_changelog(&config::load_from_path(&config_path)?, dir)?,
+            false => changelog::find_changelog_in_dir(dir)?,
+        },
+    };
+
+    Ok(path.canonicalize()?)
+}
@@ -0,0 +1,75 @@
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// Represents the severity of a problem in the changelog.
+///
+/// NOTE: Structural problems, that affect the parsed contents of the changelog,
+/// are errors, while formatting nits are informational and all other style
+/// problems are warnings.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+/// Represents a problem found in the changelog together with its severity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Problem {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Problem {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Problem {
+        Problem {
+            severity,
+            message: message.into(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Problem {
+        Problem::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Problem {
+        Problem::new(Severity::Warning, message)
+    }
+
+    pub fn info(message: impl Into<String>) -> Problem {
+        Problem::new(Severity::Info, message)
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl AsRef<str> for Problem {
+    fn as_ref(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl PartialEq<&str> for Problem {
+    fn eq(&self, other: &&str) -> bool {
+        self.message.eq(other)
+    }
+}
+
+impl PartialEq<String> for Problem {
+    fn eq(&self, other: &String) -> bool {
+        self.message.eq(other)
+    }
+}
+
+// NOTE: problems are serialized as their messages to keep the exported changelog readable.
+impl Serialize for Problem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.message.as_str())
+    }
+}
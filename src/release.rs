@@ -1,14 +1,23 @@
-use crate::{change_type::ChangeType, config, errors::ReleaseError, version};
+use crate::{change_type::ChangeType, config, errors::ReleaseError, problem::Problem, version};
+use chrono::NaiveDate;
 use regex::RegexBuilder;
+use serde::Serialize;
 
 /// Holds the information about a release section in the changelog.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Release {
     pub line: String,
+    /// The line number of the release header in the changelog.
+    pub line_number: usize,
     pub fixed: String,
     pub version: String,
+    /// The release date if it is given in the release header.
+    pub date: Option<String>,
     pub change_types: Vec<ChangeType>,
-    pub problems: Vec<String>,
+    /// The reference link definitions (e.g. `[pr1]: https://...`) found in the release section.
+    #[serde(skip)]
+    pub link_definitions: Vec<String>,
+    pub problems: Vec<Problem>,
 }
 
 impl Release {
@@ -31,15 +40,48 @@ impl Release {
 
         Ok(!parsed_version.gt(&legacy_version))
     }
+
+    /// Returns the fixed contents of the release section.
+    pub fn get_fixed_contents(&self) -> String {
+        let mut exported_string = format!("{}\n", self.fixed);
+
+        for change_type in &self.change_types {
+            exported_string.push('\n');
+            exported_string.push_str(change_type.fixed.as_str());
+            exported_string.push_str("\n\n");
+
+            for entry in &change_type.entries {
+                exported_string.push_str(entry.fixed.as_str());
+                exported_string.push('\n');
+
+                entry
+                    .sub_items
+                    .iter()
+                    .for_each(|s| exported_string.push_str(format!("{s}\n").as_str()));
+            }
+        }
+
+        if !self.link_definitions.is_empty() {
+            exported_string.push('\n');
+            self.link_definitions
+                .iter()
+                .for_each(|l| exported_string.push_str(format!("{l}\n").as_str()));
+        }
+
+        exported_string
+    }
 }
 
 /// Returns a new Release instance for the unreleased section without any contained blocks.
 pub fn new_unreleased() -> Release {
     Release {
         line: "## Unreleased".to_string(),
+        line_number: 0,
         fixed: "## Unreleased".to_string(),
         version: "Unreleased".to_string(),
+        date: None,
         change_types: Vec::new(),
+        link_definitions: Vec::new(),
         problems: Vec::new(),
     }
 }
@@ -47,9 +89,12 @@ pub fn new_unreleased() -> Release {
 pub fn new_empty_release() -> Release {
     Release {
         line: "".to_string(),
+        line_number: 0,
         fixed: "".to_string(),
         version: "".to_string(),
+        date: None,
         change_types: Vec::new(),
+        link_definitions: Vec::new(),
         problems: Vec::new(),
     }
 }
@@ -57,26 +102,50 @@ pub fn new_empty_release() -> Release {
 /// Parses the contents of a release line in the changelog.
 pub fn parse(config: &config::Config, line: &str) -> Result<Release, ReleaseError> {
     let change_types: Vec<ChangeType> = Vec::new();
-    let mut problems: Vec<String> = Vec::new();
+    let mut problems: Vec<Problem> = Vec::new();
 
     // Check unreleased pattern
     if let Some(r) = check_unreleased(line) {
         return Ok(r);
     }
 
-    let captures = match RegexBuilder::new(concat!(
-        r#"^\s*##\s*\[(?P<version>v\d+\.\d+\.\d+(-rc\d+)?)]"#,
-        r#"(?P<link>\(.*\))?\s*-\s*(?P<date>\d{4}-\d{2}-\d{2})$"#,
-    ))
-    .case_insensitive(true)
-    .build()?
-    .captures(line)
+    // NOTE: if a custom date format is configured, any text after the dash
+    // is considered as the date and validated against the format below.
+    let date_pattern = match config.date_format {
+        Some(_) => r"\S.*?",
+        None => r"\d{4}-\d{2}-\d{2}",
+    };
+
+    let pattern = [
+        r#"^\s*##\s*(\[(?P<version>v\d+\.\d+\.\d+(-rc\d+)?)]|(?P<bare>v\d+\.\d+\.\d+(-rc\d+)?))"#,
+        r#"(?P<link>\(.*\))?(\s*-\s*(?P<date>"#,
+        date_pattern,
+        r#"))?\s*$"#,
+    ]
+    .concat();
+
+    let captures = match RegexBuilder::new(pattern.as_str())
+        .case_insensitive(true)
+        .build()?
+        .captures(line)
     {
         Some(c) => c,
         None => return Err(ReleaseError::NoMatchFound),
     };
 
-    let version = captures.name("version").unwrap().as_str().to_string();
+    // NOTE: release headers without the version in brackets are only accepted in lenient mode,
+    // while missing dates are always reported as a problem.
+    if captures.name("bare").is_some() && !config.lenient_releases {
+        return Err(ReleaseError::NoMatchFound);
+    }
+
+    // NOTE: calling unwrap is okay here because one of the version groups has to match
+    let version = captures
+        .name("version")
+        .or(captures.name("bare"))
+        .unwrap()
+        .as_str()
+        .to_string();
 
     let link = match captures.name("link") {
         Some(c) => {
@@ -91,14 +160,33 @@ pub fn parse(config: &config::Config, line: &str) -> Result<Release, ReleaseErro
     let (fixed_link, link_problems) = check_link(config, link.as_str(), version.as_str());
     link_problems.into_iter().for_each(|p| problems.push(p));
 
-    let date = captures.name("date").unwrap().as_str();
-    let fixed = format!("## [{version}]({fixed_link}) - {date}");
+    let fixed = match captures.name("date") {
+        Some(date) => {
+            if NaiveDate::parse_from_str(date.as_str(), config.get_date_format()).is_err() {
+                problems.push(Problem::error(format!(
+                    "release {version} has an invalid date: '{}'",
+                    date.as_str()
+                )));
+            }
+
+            format!("## [{version}]({fixed_link}) - {}", date.as_str())
+        }
+        None => {
+            problems.push(Problem::warning(format!(
+                "release {version} is missing a date"
+            )));
+            format!("## [{version}]({fixed_link})")
+        }
+    };
 
     Ok(Release {
         line: line.to_string(),
+        line_number: 0,
         fixed,
         version,
+        date: captures.name("date").map(|d| d.as_str().to_string()),
         change_types,
+        link_definitions: Vec::new(),
         problems,
     })
 }
@@ -111,20 +199,23 @@ fn check_unreleased(line: &str) -> Option<Release> {
         .is_match(line)
     {
         let fixed = "## Unreleased".to_string();
-        let mut problems: Vec<String> = Vec::new();
+        let mut problems: Vec<Problem> = Vec::new();
         let change_types: Vec<ChangeType> = Vec::new();
 
         if fixed.ne(line) {
-            problems.push(format!(
+            problems.push(Problem::warning(format!(
                 "Unreleased header is malformed; expected: '{fixed}'; got: '{line}'"
-            ))
+            )))
         }
 
         return Some(Release {
             line: line.to_string(),
+            line_number: 0,
             fixed,
             version: "Unreleased".to_string(),
+            date: None,
             change_types,
+            link_definitions: Vec::new(),
             problems,
         });
     }
@@ -142,6 +233,7 @@ mod release_tests {
         let release = parse(&load_test_config(), example).expect("failed to parse release");
         assert_eq!(release.fixed, example);
         assert_eq!(release.version, "v0.1.0");
+        assert_eq!(release.date, Some("2024-04-27".to_string()));
         assert!(release.problems.is_empty());
     }
 
@@ -176,6 +268,88 @@ mod release_tests {
         assert_eq!(err, ReleaseError::NoMatchFound);
     }
 
+    #[test]
+    fn test_fail_bare_version_without_lenient_mode() {
+        let err = parse(&load_test_config(), "## v1.2.0").expect_err("expected parsing to fail");
+        assert_eq!(err, ReleaseError::NoMatchFound);
+    }
+
+    #[test]
+    fn test_bare_version_in_lenient_mode() {
+        let mut config = load_test_config();
+        config.lenient_releases = true;
+
+        let release = parse(&config, "## v1.2.0").expect("failed to parse release");
+        assert_eq!(release.version, "v1.2.0");
+        assert_eq!(
+            release.fixed,
+            "## [v1.2.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v1.2.0)"
+        );
+        assert_eq!(
+            release.problems,
+            vec![
+                "Release link is missing for version v1.2.0",
+                "release v1.2.0 is missing a date",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_link_and_date_in_lenient_mode() {
+        let mut config = load_test_config();
+        config.lenient_releases = true;
+
+        let release = parse(&config, "## [v1.2.0]").expect("failed to parse release");
+        assert_eq!(release.version, "v1.2.0");
+        assert_eq!(
+            release.problems,
+            vec![
+                "Release link is missing for version v1.2.0",
+                "release v1.2.0 is missing a date",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_date() {
+        let example =
+            "## [v1.2.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v1.2.0)";
+        let release = parse(&load_test_config(), example).expect("failed to parse release");
+        assert_eq!(release.version, "v1.2.0");
+        assert_eq!(release.fixed, example);
+        assert_eq!(release.problems, vec!["release v1.2.0 is missing a date"]);
+    }
+
+    #[test]
+    fn test_impossible_date() {
+        let example = "## [v0.1.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v0.1.0) - 2024-13-40";
+        let release = parse(&load_test_config(), example).expect("failed to parse release");
+        assert_eq!(release.fixed, example);
+        assert_eq!(
+            release.problems,
+            vec!["release v0.1.0 has an invalid date: '2024-13-40'"]
+        );
+    }
+
+    #[test]
+    fn test_custom_date_format() {
+        let mut config = load_test_config();
+        config.date_format = Some("%d.%m.%Y".to_string());
+
+        let example = "## [v0.1.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v0.1.0) - 27.04.2024";
+        let release = parse(&config, example).expect("failed to parse release");
+        assert_eq!(release.fixed, example);
+        assert_eq!(release.date, Some("27.04.2024".to_string()));
+        assert!(release.problems.is_empty());
+
+        let example = "## [v0.1.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v0.1.0) - 2024-04-27";
+        let release = parse(&config, example).expect("failed to parse release");
+        assert_eq!(
+            release.problems,
+            vec!["release v0.1.0 has an invalid date: '2024-04-27'"]
+        );
+    }
+
     #[test]
     fn test_missing_link() {
         let example = "## [v0.1.0] - 2024-04-27";
@@ -204,21 +378,31 @@ mod release_tests {
     }
 }
 
-fn check_link(config: &config::Config, link: &str, version: &str) -> (String, Vec<String>) {
-    let mut problems: Vec<String> = Vec::new();
+fn check_link(config: &config::Config, link: &str, version: &str) -> (String, Vec<Problem>) {
+    let mut problems: Vec<Problem> = Vec::new();
 
-    let fixed_link = format!("{}/releases/tag/{}", &config.target_repo, version);
+    let fixed_link = format!(
+        "{}/{}/{}",
+        &config.target_repo,
+        config.forge.release_path(),
+        version
+    );
 
     if link.is_empty() {
         // NOTE: returning here because the following checks are not relevant without a link
         return (
             fixed_link,
-            vec![format!("Release link is missing for version {version}")],
+            vec![Problem::warning(format!(
+                "Release link is missing for version {version}"
+            ))],
         );
     }
 
     if link != fixed_link {
-        problems.push(format!("Release link should point to the GitHub release for {version}; expected: '{fixed_link}'; got: '{link}'"))
+        problems.push(Problem::warning(format!(
+            "Release link should point to the {} for {version}; expected: '{fixed_link}'; got: '{link}'",
+            config.forge.release_page_name()
+        )))
     }
 
     (fixed_link, problems)
@@ -272,6 +456,28 @@ mod link_tests {
         ]);
     }
 
+    #[test]
+    fn test_pass_gitlab() {
+        let mut config = load_test_config();
+        config.forge = config::ForgeKind::GitLab;
+        let example = "https://github.com/MalteHerrmann/changelog-utils/-/tags/v0.1.0";
+        let (fixed, problems) = check_link(&config, example, "v0.1.0");
+        assert_eq!(fixed, example);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_gitlab_rejects_github_path() {
+        let mut config = load_test_config();
+        config.forge = config::ForgeKind::GitLab;
+        let example = "https://github.com/MalteHerrmann/changelog-utils/releases/tag/v0.1.0";
+        let (fixed, problems) = check_link(&config, example, "v0.1.0");
+        assert_eq!(fixed, example.replace("releases/tag", "-/tags"));
+        assert_eq!(problems, vec![
+            format!("Release link should point to the GitLab tag for v0.1.0; expected: '{fixed}'; got: '{example}'")
+        ]);
+    }
+
     #[test]
     fn test_link_is_correct_version_and_base_url_but_more_elements() {
         let example =
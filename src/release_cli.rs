@@ -1,23 +1,41 @@
 use crate::{
     changelog::{self, Changelog},
-    config,
+    cli::ReleaseArgs,
+    config::{self, Config},
     errors::ReleaseCLIError,
-    inputs::get_release_type,
+    inputs::{get_permission_to_release, get_release_type},
     release::Release,
+    release_type::ReleaseType,
     version,
 };
-use chrono::offset::Local;
+use chrono::{offset::Local, NaiveDate};
 
 /// Creates a new release with the given version based on the given version.
-pub fn run(version_option: Option<String>) -> Result<(), ReleaseCLIError> {
+pub fn run(args: ReleaseArgs) -> Result<(), ReleaseCLIError> {
     let config = config::load()?;
     let mut changelog = changelog::load(config.clone())?;
 
-    let version = match version_option {
-        Some(v) => version::parse(v.as_str())?,
-        None => get_next_release_version(&changelog)?,
+    let date = get_release_date(&config, args.date)?;
+    let version = match args.version {
+        Some(v) => v,
+        None => query_next_release_version(&changelog)?,
     };
 
+    add_release(&config, &mut changelog, &version, &date)?;
+
+    Ok(changelog.write(&changelog.path)?)
+}
+
+/// Moves the contents of the unreleased section into a new release with the given
+/// version and date. The Unreleased header is kept without any contents.
+pub fn add_release(
+    config: &Config,
+    changelog: &mut Changelog,
+    version: &str,
+    date: &str,
+) -> Result<(), ReleaseCLIError> {
+    let version = version::parse(version)?;
+
     if changelog
         .releases
         .iter()
@@ -26,45 +44,170 @@ pub fn run(version_option: Option<String>) -> Result<(), ReleaseCLIError> {
         return Err(ReleaseCLIError::DuplicateVersion(version.to_string()));
     }
 
-    let unreleased = match changelog.releases.iter_mut().find(|x| x.is_unreleased()) {
-        Some(r) => r,
+    if let Some(latest) = changelog
+        .releases
+        .iter()
+        .filter_map(|r| version::parse(r.version.as_str()).ok())
+        .find(|v| !version.gt(v))
+    {
+        return Err(ReleaseCLIError::VersionNotGreater(
+            version.to_string(),
+            latest.to_string(),
+        ));
+    }
+
+    let idx = match changelog.releases.iter().position(|x| x.is_unreleased()) {
+        Some(i) => i,
         None => return Err(ReleaseCLIError::NoUnreleased),
     };
 
-    let today = Local::now();
+    let header = get_release_header(config, &version.to_string(), date);
+    let new_release = Release {
+        line: header.clone(),
+        line_number: 0,
+        fixed: header,
+        version: version.to_string(),
+        date: Some(date.to_string()),
+        change_types: std::mem::take(&mut changelog.releases[idx].change_types),
+        link_definitions: std::mem::take(&mut changelog.releases[idx].link_definitions),
+        problems: Vec::new(),
+    };
+
+    changelog.releases.insert(idx + 1, new_release);
+
+    Ok(())
+}
+
+/// Returns the given release date after validating it against the configured
+/// date format or today's date if no date is given.
+pub fn get_release_date(config: &Config, date: Option<String>) -> Result<String, ReleaseCLIError> {
+    let date_format = config.get_valid_date_format()?;
 
-    unreleased.version.clone_from(&version.to_string());
-    unreleased.fixed = format!(
-        "## [{0}]({1}/releases/tag/{0}) - {2}",
+    match date {
+        Some(d) => match NaiveDate::parse_from_str(d.as_str(), date_format) {
+            Ok(_) => Ok(d),
+            Err(_) => Err(ReleaseCLIError::InvalidDate(d)),
+        },
+        None => Ok(Local::now().date_naive().format(date_format).to_string()),
+    }
+}
+
+/// Returns the header line for the release with the given version and date.
+pub fn get_release_header(config: &Config, version: &str, date: &str) -> String {
+    format!(
+        "## [{0}]({1}/{2}/{0}) - {3}",
         version,
         &config.target_repo,
-        today.date_naive()
-    );
-
-    Ok(changelog.write(&changelog.path)?)
+        config.forge.release_path(),
+        date
+    )
 }
 
 /// Queries the user for the desired release type and then derives the required
 /// upgraded version from the existing releases.
+/// The resulting version has to be confirmed by the user.
+fn query_next_release_version(changelog: &Changelog) -> Result<String, ReleaseCLIError> {
+    let release_type = get_release_type()?;
+    let version = get_next_release_version(changelog, &release_type)?.to_string();
+
+    if !get_permission_to_release(&version)? {
+        return Err(ReleaseCLIError::NotConfirmed(version));
+    }
+
+    Ok(version)
+}
+
+/// Derives the upgraded version for the given release type from the latest release.
 ///
-/// Example: If a user selects a patch release with the latest version being `1.2.3`,
+/// Example: If a patch release is selected with the latest version being `1.2.3`,
 /// the released version would be `1.2.4`.
-fn get_next_release_version(changelog: &Changelog) -> Result<version::Version, ReleaseCLIError> {
-    let mut prior_releases: Vec<&Release> = changelog
+fn get_next_release_version(
+    changelog: &Changelog,
+    release_type: &ReleaseType,
+) -> Result<version::Version, ReleaseCLIError> {
+    let latest_version = changelog
         .releases
         .iter()
-        .filter(|x| !x.is_unreleased())
-        .collect();
+        .filter_map(|r| version::parse(r.version.as_str()).ok())
+        .reduce(|latest, v| if v.gt(&latest) { v } else { latest })
+        .ok_or(ReleaseCLIError::NoPriorRelease)?;
 
-    // TODO: this should be done when saving the changelog
-    prior_releases.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(version::bump_version(&latest_version, release_type))
+}
 
-    let latest_release = prior_releases.last().unwrap();
-    let latest_version = version::parse(&latest_release.version)?;
+#[cfg(test)]
+mod release_cli_tests {
+    use super::*;
+    use crate::{errors::ConfigError, release};
 
-    let release_type = get_release_type()?;
+    fn load_test_config() -> Config {
+        config::unpack_config(include_str!("testdata/example_config.json"))
+            .expect("failed to load example config")
+    }
+
+    #[test]
+    fn test_release_header_parses() {
+        let config = load_test_config();
+        let date = get_release_date(&config, None).expect("failed to get release date");
 
-    let new_version = version::bump_version(&latest_version, &release_type);
+        let header = get_release_header(&config, "v3.0.0", &date);
+        let parsed = release::parse(&config, &header).expect("failed to parse release header");
+        assert_eq!(parsed.fixed, header);
+        assert_eq!(parsed.date, Some(date));
+        assert!(parsed.problems.is_empty());
+    }
 
-    Ok(new_version)
+    #[test]
+    fn test_next_release_version() {
+        let changelog = changelog::parse_changelog_str(
+            load_test_config(),
+            concat!(
+                "# Changelog\n\n",
+                "## Unreleased\n\n",
+                "## [v9.0.0](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v9.0.0) - 2024-04-27\n\n",
+                "## [v10.1.2](https://github.com/MalteHerrmann/changelog-utils/releases/tag/v10.1.2) - 2024-04-28\n",
+            ),
+            std::path::Path::new("CHANGELOG.md"),
+        )
+        .expect("failed to parse changelog");
+
+        for (release_type, expected) in [
+            (ReleaseType::Major, "v11.0.0"),
+            (ReleaseType::Minor, "v10.2.0"),
+            (ReleaseType::Patch, "v10.1.3"),
+            (ReleaseType::RcPatch, "v10.1.3-rc1"),
+        ] {
+            assert_eq!(
+                get_next_release_version(&changelog, &release_type)
+                    .expect("failed to get next version")
+                    .to_string(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_release_date() {
+        let config = load_test_config();
+        assert_eq!(
+            get_release_date(&config, Some("2024-04-27".into())).expect("failed to get date"),
+            "2024-04-27"
+        );
+        assert!(matches!(
+            get_release_date(&config, Some("2024-13-40".into())),
+            Err(ReleaseCLIError::InvalidDate(d)) if d == "2024-13-40"
+        ));
+    }
+
+    #[test]
+    fn test_release_date_invalid_format() {
+        for date_format in ["%Y-%m-%", "%Y-%m-%d %H:%M"] {
+            let mut config = load_test_config();
+            config.date_format = Some(date_format.into());
+            assert!(matches!(
+                get_release_date(&config, None),
+                Err(ReleaseCLIError::Config(ConfigError::InvalidDateFormat(f))) if f == date_format
+            ));
+        }
+    }
 }
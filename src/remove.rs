@@ -0,0 +1,47 @@
+use crate::{changelog, cli::RemoveArgs, config, entry, errors::RemoveError};
+
+// Runs the logic to remove the entry with the given PR number from the
+// unreleased section of the changelog.
+pub fn run(args: RemoveArgs) -> Result<(), RemoveError> {
+    let config = config::load()?;
+    let mut changelog = changelog::load(config)?;
+
+    remove_entry(&mut changelog, args.pr_number)?;
+
+    Ok(changelog.write(&changelog.path)?)
+}
+
+/// Removes the entry with the given PR number from the unreleased section
+/// of the changelog and returns it.
+///
+/// If the corresponding change type does not contain any more entries afterwards,
+/// it is removed from the release as well.
+pub fn remove_entry(
+    changelog: &mut changelog::Changelog,
+    pr: u64,
+) -> Result<entry::Entry, RemoveError> {
+    let unreleased = match changelog.releases.iter_mut().find(|r| r.is_unreleased()) {
+        Some(r) => r,
+        None => return Err(RemoveError::NotFound(pr)),
+    };
+
+    let mut removed: Option<entry::Entry> = None;
+    for ct in unreleased.change_types.iter_mut() {
+        if let Some(idx) = ct
+            .entries
+            .iter()
+            .position(|e| e.pr_number == pr || e.pr_numbers.contains(&pr))
+        {
+            removed = Some(ct.entries.remove(idx));
+            break;
+        }
+    }
+
+    let Some(removed_entry) = removed else {
+        return Err(RemoveError::NotFound(pr));
+    };
+
+    unreleased.change_types.retain(|ct| !ct.entries.is_empty());
+
+    Ok(removed_entry)
+}
@@ -0,0 +1,198 @@
+use crate::{
+    changelog,
+    changelog::Changelog,
+    cli::StatsArgs,
+    config,
+    errors::StatsError,
+    github::{get_git_info, get_github_client, get_pr_author},
+    pager,
+    release::Release,
+};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+/// Holds the summarized information about the changelog contents.
+#[derive(Debug, Default, Serialize)]
+pub struct Stats {
+    /// The number of released versions (excluding the unreleased section).
+    pub releases: usize,
+    /// The total number of entries in the changelog.
+    pub entries: usize,
+    /// The number of entries per change type.
+    pub entries_per_change_type: BTreeMap<String, usize>,
+    /// The number of entries per category.
+    pub entries_per_category: BTreeMap<String, usize>,
+    /// The date of the oldest release.
+    pub oldest_release_date: Option<String>,
+    /// The date of the newest release.
+    pub newest_release_date: Option<String>,
+    /// The number of days between the oldest and the newest release.
+    pub days_between_releases: Option<i64>,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "releases: {}", self.releases)?;
+        writeln!(f, "entries: {}", self.entries)?;
+
+        writeln!(f, "\nentries per change type:")?;
+        for (change_type, count) in &self.entries_per_change_type {
+            writeln!(f, "  {change_type}: {count}")?;
+        }
+
+        writeln!(f, "\nentries per category:")?;
+        for (category, count) in &self.entries_per_category {
+            writeln!(f, "  {category}: {count}")?;
+        }
+
+        if let (Some(oldest), Some(newest), Some(days)) = (
+            &self.oldest_release_date,
+            &self.newest_release_date,
+            self.days_between_releases,
+        ) {
+            writeln!(f, "\nreleases between {oldest} and {newest} ({days} days)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs the logic to print the statistics of the changelog in the
+/// current directory.
+pub async fn run(args: StatsArgs) -> Result<(), StatsError> {
+    let config = config::load()?;
+    let changelog = changelog::load(config.clone())?;
+
+    if args.by_author {
+        let release = changelog
+            .releases
+            .iter()
+            .find(|r| r.version.eq(&args.release))
+            .ok_or(StatsError::ReleaseNotFound(args.release.clone()))?;
+
+        let authors = get_authors(&config, release).await?;
+        let counts = count_entries_by_author(release, |pr| authors.get(&pr).cloned().flatten());
+
+        match args.json {
+            true => println!("{}", serde_json::to_string_pretty(&counts)?),
+            false => counts
+                .iter()
+                .for_each(|(author, n)| println!("{author}: {n} entries")),
+        }
+
+        return Ok(());
+    }
+
+    let stats = get_stats(&config, &changelog);
+
+    match args.json {
+        true => println!("{}", serde_json::to_string_pretty(&stats)?),
+        false => pager::print(stats.to_string().as_str(), args.no_pager),
+    }
+
+    Ok(())
+}
+
+/// Fetches the authors for all PRs referenced in the given release.
+///
+/// NOTE: The authors are cached per PR number, so that every PR is only fetched once.
+/// If no GitHub token is available, the requests are unauthenticated and failed requests
+/// result in an unknown author.
+async fn get_authors(
+    config: &config::Config,
+    release: &Release,
+) -> Result<HashMap<u64, Option<String>>, StatsError> {
+    let git_info = get_git_info(config)?;
+    let client = get_github_client();
+
+    let mut authors: HashMap<u64, Option<String>> = HashMap::new();
+    for change_type in &release.change_types {
+        for entry in &change_type.entries {
+            if authors.contains_key(&entry.pr_number) {
+                continue;
+            }
+
+            let author = get_pr_author(config, &client, &git_info, entry.pr_number)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("failed to get author for PR #{}: {}", entry.pr_number, e);
+                    None
+                });
+            authors.insert(entry.pr_number, author);
+        }
+    }
+
+    Ok(authors)
+}
+
+/// Counts the entries in the given release per PR author, which is
+/// looked up for each PR number with the given function.
+///
+/// NOTE: Entries, whose author could not be determined, are counted as `unknown`.
+pub fn count_entries_by_author(
+    release: &Release,
+    mut get_author: impl FnMut(u64) -> Option<String>,
+) -> BTreeMap<String, usize> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    for change_type in &release.change_types {
+        for entry in &change_type.entries {
+            let author = get_author(entry.pr_number).unwrap_or("unknown".to_string());
+            *counts.entry(author).or_default() += 1;
+        }
+    }
+
+    counts
+}
+
+/// Summarizes the contents of the given changelog.
+///
+/// NOTE: The release dates are parsed and printed in the configured date format.
+pub fn get_stats(config: &config::Config, changelog: &Changelog) -> Stats {
+    let date_format = config.get_date_format();
+    let mut stats = Stats::default();
+    let mut dates: Vec<NaiveDate> = Vec::new();
+
+    for release in &changelog.releases {
+        if !release.is_unreleased() {
+            stats.releases += 1;
+        }
+
+        if let Some(d) = release
+            .date
+            .as_ref()
+            .and_then(|d| NaiveDate::parse_from_str(d, date_format).ok())
+        {
+            dates.push(d);
+        }
+
+        for change_type in &release.change_types {
+            for entry in &change_type.entries {
+                stats.entries += 1;
+                *stats
+                    .entries_per_change_type
+                    .entry(change_type.name.clone())
+                    .or_default() += 1;
+
+                if !entry.category.is_empty() {
+                    *stats
+                        .entries_per_category
+                        .entry(entry.category.clone())
+                        .or_default() += 1;
+                }
+            }
+        }
+    }
+
+    if let (Some(oldest), Some(newest)) = (dates.iter().min(), dates.iter().max()) {
+        stats.oldest_release_date = Some(oldest.format(date_format).to_string());
+        stats.newest_release_date = Some(newest.format(date_format).to_string());
+        stats.days_between_releases = Some((*newest - *oldest).num_days());
+    }
+
+    stats
+}
@@ -0,0 +1,86 @@
+use crate::{
+    changelog,
+    changelog::Changelog,
+    cli::VerifyLinksArgs,
+    config,
+    errors::VerifyLinksError,
+    github::{get_git_info, get_github_client, get_pr_by_number},
+};
+use std::collections::BTreeMap;
+
+/// Runs the logic to verify that all PRs linked in the changelog
+/// exist in the target repository.
+pub async fn run(args: VerifyLinksArgs) -> Result<(), VerifyLinksError> {
+    let config = config::load()?;
+    let changelog = changelog::load(config.clone())?;
+    let git_info = get_git_info(&config)?;
+    let client = get_github_client();
+
+    let mut found: BTreeMap<u64, bool> = BTreeMap::new();
+    for pr_number in get_pr_numbers(&changelog, args.unreleased_only) {
+        let exists = get_pr_by_number(&config, &client, &git_info, pr_number)
+            .await?
+            .is_some();
+        found.insert(pr_number, exists);
+    }
+
+    let broken_links = get_broken_links(&changelog, args.unreleased_only, |pr| {
+        found.get(&pr).copied().unwrap_or(true)
+    });
+    broken_links.iter().for_each(|b| println!("{b}"));
+
+    match broken_links.len() {
+        0 => {
+            println!("all linked PRs exist in {}", config.target_repo);
+            Ok(())
+        }
+        n => Err(VerifyLinksError::BrokenLinks(n)),
+    }
+}
+
+/// Returns the deduplicated PR numbers referenced in the changelog entries.
+fn get_pr_numbers(changelog: &Changelog, unreleased_only: bool) -> Vec<u64> {
+    let mut pr_numbers: Vec<u64> = changelog
+        .releases
+        .iter()
+        .filter(|r| !unreleased_only || r.is_unreleased())
+        .flat_map(|r| r.change_types.iter())
+        .flat_map(|ct| ct.entries.iter())
+        .flat_map(|e| e.pr_numbers.iter().copied())
+        .collect();
+
+    pr_numbers.sort_unstable();
+    pr_numbers.dedup();
+    pr_numbers
+}
+
+/// Returns the descriptions of the linked PRs, that do not exist
+/// according to the given lookup function.
+pub fn get_broken_links(
+    changelog: &Changelog,
+    unreleased_only: bool,
+    mut exists: impl FnMut(u64) -> bool,
+) -> Vec<String> {
+    let mut broken_links: Vec<String> = Vec::new();
+
+    for release in changelog
+        .releases
+        .iter()
+        .filter(|r| !unreleased_only || r.is_unreleased())
+    {
+        for change_type in &release.change_types {
+            for entry in &change_type.entries {
+                for pr_number in &entry.pr_numbers {
+                    if !exists(*pr_number) {
+                        broken_links.push(format!(
+                            "{}: PR #{} not found in target repository: {}",
+                            release.version, pr_number, entry.line
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    broken_links
+}
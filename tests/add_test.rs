@@ -1,6 +1,6 @@
-use assert_fs::NamedTempFile;
-use clu::{add, changelog, config};
-use std::{borrow::BorrowMut, path::Path};
+use assert_fs::{prelude::*, NamedTempFile};
+use clu::{add, changelog, cli::AddArgs, config, errors::AddError, github};
+use std::{borrow::BorrowMut, fs, path::Path};
 
 #[cfg(test)]
 fn load_example_config() -> config::Config {
@@ -25,15 +25,17 @@ fn test_pass_add_into_new_change_type() {
         "test",
         "Test object.",
         15,
-    );
+        None,
+    )
+    .expect("failed to add entry");
 
-    let first_release = changelog.releases.get(0).unwrap();
+    let first_release = changelog.releases.first().unwrap();
     assert_eq!(first_release.change_types.len(), 3);
     let new_change_type = first_release.change_types.get(2).unwrap();
     assert_eq!(new_change_type.name, "Bug Fixes");
     assert_eq!(new_change_type.entries.len(), 1);
 
-    let added_entry = new_change_type.entries.get(0).unwrap();
+    let added_entry = new_change_type.entries.first().unwrap();
     assert_eq!(added_entry.pr_number, 15);
     assert_eq!(
         added_entry.fixed,
@@ -58,16 +60,18 @@ fn test_pass_add_with_no_unreleased_section() {
         "test",
         "Test object.",
         15,
-    );
+        None,
+    )
+    .expect("failed to add entry");
 
     assert_eq!(changelog.releases.len(), 3);
-    let first_release = changelog.releases.get(0).unwrap();
+    let first_release = changelog.releases.first().unwrap();
     assert_eq!(first_release.change_types.len(), 1);
-    let new_change_type = first_release.change_types.get(0).unwrap();
+    let new_change_type = first_release.change_types.first().unwrap();
     assert_eq!(new_change_type.name, "Bug Fixes");
     assert_eq!(new_change_type.entries.len(), 1);
 
-    let added_entry = new_change_type.entries.get(0).unwrap();
+    let added_entry = new_change_type.entries.first().unwrap();
     assert_eq!(added_entry.pr_number, 15);
     assert_eq!(
         added_entry.fixed,
@@ -92,7 +96,9 @@ fn test_pass_add_new_with_auto_fix() {
         "all",
         "adding an entry that's auto-fixable",
         15,
-    );
+        None,
+    )
+    .expect("failed to add entry");
 
     // export to temporary file
     let tmp_path = NamedTempFile::new("tmp_changelog.md").expect("failed to save tmp changelog");
@@ -103,13 +109,13 @@ fn test_pass_add_new_with_auto_fix() {
     let updated_changelog = changelog::parse_changelog(config.clone(), tmp_path.path()).unwrap();
     let added_entry = updated_changelog
         .releases
-        .get(0)
+        .first()
         .unwrap()
         .change_types
         .get(2)
         .unwrap()
         .entries
-        .get(0)
+        .first()
         .unwrap();
 
     // NOTE: we're expecting to have the first letter capitalized and the dot at the end added
@@ -119,3 +125,271 @@ fn test_pass_add_new_with_auto_fix() {
         "expected line to have been corrected before writing to changelog."
     );
 }
+
+#[test]
+fn test_fail_add_unknown_change_type_in_closed_set() {
+    let mut config = load_example_config();
+    config.closed_change_types = true;
+    let mut changelog = changelog::parse_changelog(
+        config.clone(),
+        Path::new("tests/testdata/changelog_no_unreleased.md"),
+    )
+    .expect("failed to parse example changelog");
+
+    let err = add::add_entry(
+        &config,
+        changelog.borrow_mut(),
+        "Invalid Type",
+        "test",
+        "Test object.",
+        15,
+        None,
+    )
+    .expect_err("expected adding to fail");
+    assert!(matches!(err, AddError::InvalidChangeType(ct) if ct == "Invalid Type"));
+    assert_eq!(changelog.releases.len(), 2);
+}
+
+#[test]
+fn test_pass_add_unknown_change_type_in_open_set() {
+    let config = load_example_config();
+    let mut changelog = changelog::parse_changelog(
+        config.clone(),
+        Path::new("tests/testdata/changelog_no_unreleased.md"),
+    )
+    .expect("failed to parse example changelog");
+
+    add::add_entry(
+        &config,
+        changelog.borrow_mut(),
+        "Invalid Type",
+        "test",
+        "Test object.",
+        15,
+        None,
+    )
+    .expect("failed to add entry");
+    assert_eq!(changelog.releases.len(), 3);
+}
+
+#[test]
+fn test_pass_placeholder_in_empty_unreleased() {
+    let mut config = load_example_config();
+    config.unreleased_placeholder = Some("_No unreleased changes yet._".to_string());
+
+    let changelog = changelog::parse_changelog(
+        config.clone(),
+        Path::new("tests/testdata/changelog_empty_unreleased.md"),
+    )
+    .expect("failed to parse example changelog");
+    assert!(changelog.problems.is_empty());
+
+    let unreleased = changelog.releases.first().unwrap();
+    assert!(unreleased.is_unreleased());
+    assert!(unreleased.change_types.is_empty());
+    assert!(changelog
+        .get_fixed_contents()
+        .contains("## Unreleased\n\n_No unreleased changes yet._\n\n## [v15.1.0]"));
+}
+
+#[test]
+fn test_pass_placeholder_removed_after_add() {
+    let mut config = load_example_config();
+    config.unreleased_placeholder = Some("_No unreleased changes yet._".to_string());
+
+    let mut changelog = changelog::parse_changelog(
+        config.clone(),
+        Path::new("tests/testdata/changelog_empty_unreleased.md"),
+    )
+    .expect("failed to parse example changelog");
+
+    add::add_entry(
+        &config,
+        changelog.borrow_mut(),
+        "Bug Fixes",
+        "test",
+        "Test object.",
+        15,
+        None,
+    )
+    .expect("failed to add entry");
+
+    let contents = changelog.get_fixed_contents();
+    assert!(!contents.contains("_No unreleased changes yet._"));
+    assert!(contents.contains(
+        "## Unreleased\n\n### Bug Fixes\n\n- (test) [#15](https://github.com/evmos/evmos/pull/15) Test object.\n"
+    ));
+}
+
+#[test]
+fn test_dry_run_does_not_write_changelog() {
+    let config = load_example_config();
+    let original = include_str!("testdata/changelog_ok.md");
+    let tmp_path = NamedTempFile::new("CHANGELOG.md").expect("failed to create tmp changelog");
+    tmp_path
+        .write_str(original)
+        .expect("failed to write tmp changelog");
+
+    let mut changelog = changelog::parse_changelog(config.clone(), tmp_path.path())
+        .expect("failed to parse example changelog");
+    let new_entry = add::add_entry(
+        &config,
+        changelog.borrow_mut(),
+        "Bug Fixes",
+        "test",
+        "Test object.",
+        15,
+        None,
+    )
+    .expect("failed to add entry");
+    assert_eq!(
+        new_entry.fixed,
+        "- (test) [#15](https://github.com/evmos/evmos/pull/15) Test object."
+    );
+
+    add::write_changelog(&changelog, "Bug Fixes", &new_entry, true).expect("failed to run dry-run");
+    assert_eq!(fs::read_to_string(tmp_path.path()).unwrap(), original);
+
+    add::write_changelog(&changelog, "Bug Fixes", &new_entry, false)
+        .expect("failed to write changelog");
+    assert!(fs::read_to_string(tmp_path.path())
+        .unwrap()
+        .contains(new_entry.fixed.as_str()));
+}
+
+#[test]
+fn test_entry_inputs_from_args() {
+    let config = load_example_config();
+    let args = AddArgs {
+        change_type: Some("fix".to_string()),
+        category: Some("evm".to_string()),
+        description: Some("Fix the gas estimation.".to_string()),
+        pr: Some(15),
+        ..Default::default()
+    };
+
+    let inputs = add::get_entry_inputs_from_args(&config, &args)
+        .expect("failed to get inputs")
+        .expect("expected all inputs to be provided");
+    assert_eq!(inputs.change_type, "Bug Fixes");
+    assert_eq!(inputs.category, "evm");
+    assert_eq!(inputs.description, "Fix the gas estimation.");
+    assert_eq!(inputs.number, 15);
+}
+
+#[test]
+fn test_entry_inputs_from_incomplete_args() {
+    let config = load_example_config();
+    let args = AddArgs {
+        change_type: Some("Bug Fixes".to_string()),
+        description: Some("Fix the gas estimation.".to_string()),
+        pr: Some(15),
+        ..Default::default()
+    };
+
+    assert!(add::get_entry_inputs_from_args(&config, &args)
+        .expect("failed to get inputs")
+        .is_none());
+}
+
+#[test]
+fn test_entry_inputs_from_args_without_categories() {
+    let mut config = load_example_config();
+    config.use_categories = false;
+    let args = AddArgs {
+        change_type: Some("Bug Fixes".to_string()),
+        description: Some("Fix the gas estimation.".to_string()),
+        pr: Some(15),
+        ..Default::default()
+    };
+
+    let inputs = add::get_entry_inputs_from_args(&config, &args)
+        .expect("failed to get inputs")
+        .expect("expected all inputs to be provided");
+    assert!(inputs.category.is_empty());
+}
+
+#[test]
+fn test_entry_inputs_from_invalid_args() {
+    let config = load_example_config();
+    let args = AddArgs {
+        change_type: Some("invalid".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        add::get_entry_inputs_from_args(&config, &args),
+        Err(AddError::InvalidChangeType(ct)) if ct == "invalid"
+    ));
+
+    let args = AddArgs {
+        category: Some("invalid".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        add::get_entry_inputs_from_args(&config, &args),
+        Err(AddError::InvalidCategory(c)) if c == "invalid"
+    ));
+}
+
+#[test]
+fn test_no_commit_skips_commit() {
+    let args = AddArgs {
+        no_commit: true,
+        ..Default::default()
+    };
+
+    let mut invoked = false;
+    let committed = add::commit_entry(&args, Path::new("CHANGELOG.md"), || {
+        invoked = true;
+        Ok(())
+    })
+    .expect("failed to skip commit");
+    assert!(!committed);
+    assert!(!invoked);
+}
+
+#[test]
+fn test_commit_entry() {
+    let mut invoked = false;
+    let committed = add::commit_entry(&AddArgs::default(), Path::new("CHANGELOG.md"), || {
+        invoked = true;
+        Ok(())
+    })
+    .expect("failed to commit");
+    assert!(committed);
+    assert!(invoked);
+}
+
+#[test]
+fn test_add_entry_with_large_pr_number() {
+    let config = load_example_config();
+    let mut changelog =
+        changelog::parse_changelog(config.clone(), Path::new("tests/testdata/changelog_ok.md"))
+            .expect("failed to parse example changelog");
+
+    let new_entry = add::add_entry(
+        &config,
+        changelog.borrow_mut(),
+        "Bug Fixes",
+        "evm",
+        "Fix large PR numbers.",
+        70000,
+        None,
+    )
+    .expect("failed to add entry");
+    assert_eq!(new_entry.pr_number, 70000);
+    assert_eq!(
+        new_entry.fixed,
+        "- (evm) [#70000](https://github.com/evmos/evmos/pull/70000) Fix large PR numbers."
+    );
+}
+
+#[test]
+fn test_parse_commit_subject() {
+    let pr_info = github::parse_pr_title(&load_example_config(), "fix(evm): Handle empty input.")
+        .expect("failed to parse commit subject");
+    assert_eq!(pr_info.change_type, "Bug Fixes");
+    assert_eq!(pr_info.category, "evm");
+    assert_eq!(pr_info.description, "Handle empty input.");
+    assert_eq!(pr_info.number, 0);
+}
@@ -0,0 +1,61 @@
+use assert_fs::NamedTempFile;
+use clu::{baseline, changelog, config, problem::Problem};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_failing_changelog() -> changelog::Changelog {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+
+    changelog::parse_changelog(config, Path::new("tests/testdata/changelog_fail.md"))
+        .expect("failed to parse changelog")
+}
+
+#[test]
+fn test_pass_all_problems_in_baseline() {
+    let changelog = load_failing_changelog();
+    assert!(!changelog.problems.is_empty());
+
+    let baseline = baseline::Baseline::new(&changelog.problems);
+    assert!(baseline.filter_new_problems(&changelog.problems).is_empty());
+}
+
+#[test]
+fn test_pass_moved_problems_are_matched() {
+    let baseline = baseline::Baseline::new(&["CHANGELOG.md:10: duplicate PR: #1862".to_string()]);
+
+    assert!(baseline
+        .filter_new_problems(&["CHANGELOG.md:12: duplicate PR: #1862".to_string()])
+        .is_empty());
+}
+
+#[test]
+fn test_fail_new_problems_are_reported() {
+    let changelog = load_failing_changelog();
+    let baseline = baseline::Baseline::new(&changelog.problems);
+
+    let new_problem = Problem::error("tests/testdata/changelog_fail.md:5: duplicate PR: #1");
+    let mut problems = changelog.problems.clone();
+    problems.push(new_problem.clone());
+    // NOTE: repeated occurrences of a known problem are reported as new
+    problems.push(changelog.problems.first().unwrap().clone());
+
+    assert_eq!(
+        baseline.filter_new_problems(&problems),
+        vec![new_problem, changelog.problems.first().unwrap().clone()]
+    );
+}
+
+#[test]
+fn test_pass_write_and_load_baseline() {
+    let changelog = load_failing_changelog();
+    let baseline = baseline::Baseline::new(&changelog.problems);
+
+    let file = NamedTempFile::new("baseline.json").expect("failed to create temporary file");
+    baseline
+        .write(file.path())
+        .expect("failed to write baseline");
+
+    let loaded = baseline::load(file.path()).expect("failed to load baseline");
+    assert_eq!(loaded, baseline);
+}
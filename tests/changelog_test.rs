@@ -0,0 +1,52 @@
+use assert_fs::{prelude::*, TempDir};
+use clu::{changelog, config};
+
+#[cfg(test)]
+fn load_test_config() -> config::Config {
+    config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config")
+}
+
+#[test]
+fn test_find_changelog_in_nested_path() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child("docs/CHANGELOG.md")
+        .write_str(include_str!("testdata/changelog_ok.md"))
+        .expect("failed to write nested changelog");
+
+    let mut config = load_test_config();
+    config.changelog_path = "docs/CHANGELOG.md".to_string();
+
+    let changelog_path =
+        changelog::find_changelog(&config, temp_dir.path()).expect("failed to find changelog");
+    assert_eq!(changelog_path, temp_dir.child("docs/CHANGELOG.md").path());
+
+    let changelog = changelog::parse_changelog(config, changelog_path.as_path())
+        .expect("failed to parse nested changelog");
+    assert_eq!(changelog.releases.len(), 3);
+    assert!(changelog.problems.is_empty());
+}
+
+#[test]
+fn test_find_changelog_falls_back_to_directory_scan() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child("changelog.md")
+        .touch()
+        .expect("failed to create dummy changelog");
+
+    let mut config = load_test_config();
+    config.changelog_path = "docs/CHANGELOG.md".to_string();
+
+    let changelog_path =
+        changelog::find_changelog(&config, temp_dir.path()).expect("failed to find changelog");
+    assert_eq!(changelog_path, temp_dir.child("changelog.md").path());
+}
+
+#[test]
+fn test_find_changelog_not_found() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+    assert!(changelog::find_changelog(&load_test_config(), temp_dir.path()).is_err());
+}
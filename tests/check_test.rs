@@ -0,0 +1,72 @@
+use assert_fs::{prelude::*, TempDir};
+use clu::check::{self, CheckStatus};
+
+#[test]
+fn test_check_config_missing() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+    let (status, config) = check::check_config(temp_dir.path());
+    assert!(matches!(status, CheckStatus::Fail(_)));
+    assert!(config.is_none());
+}
+
+#[test]
+fn test_check_config_invalid() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".clconfig.json")
+        .write_str("{ invalid")
+        .expect("failed to write config");
+
+    let (status, config) = check::check_config(temp_dir.path());
+    assert!(matches!(status, CheckStatus::Fail(_)));
+    assert!(config.is_none());
+}
+
+#[test]
+fn test_check_config_and_changelog() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".clconfig.json")
+        .write_str(include_str!("testdata/evmos_config.json"))
+        .expect("failed to write config");
+
+    let (status, config) = check::check_config(temp_dir.path());
+    assert!(matches!(status, CheckStatus::Pass(_)));
+    assert!(config.is_some());
+
+    assert!(matches!(
+        check::check_changelog(temp_dir.path(), config.as_ref()),
+        CheckStatus::Fail(_)
+    ));
+
+    temp_dir
+        .child("CHANGELOG.md")
+        .write_str(include_str!("testdata/changelog_ok.md"))
+        .expect("failed to write changelog");
+    assert!(matches!(
+        check::check_changelog(temp_dir.path(), config.as_ref()),
+        CheckStatus::Pass(_)
+    ));
+}
+
+#[test]
+fn test_check_git() {
+    assert!(matches!(check::check_git(), CheckStatus::Pass(_)));
+}
+
+#[test]
+fn test_check_github_token() {
+    assert!(matches!(
+        check::check_github_token(Some("token")),
+        CheckStatus::Pass(_)
+    ));
+    assert!(matches!(
+        check::check_github_token(None),
+        CheckStatus::Warn(_)
+    ));
+    assert!(matches!(
+        check::check_github_token(Some("")),
+        CheckStatus::Warn(_)
+    ));
+}
@@ -0,0 +1,243 @@
+use assert_fs::{prelude::*, NamedTempFile};
+use clu::{changelog, config};
+use std::{fs, process::Command};
+
+#[cfg(test)]
+fn load_test_config() -> config::Config {
+    config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config")
+}
+
+#[test]
+fn test_rename_spelling_and_update_changelog() {
+    let mut config = load_test_config();
+    config
+        .expected_spellings
+        .insert("Javascript".into(), "javascript".into());
+
+    let tmp_changelog = NamedTempFile::new("CHANGELOG.md").expect("failed to create tmp file");
+    tmp_changelog
+        .write_str(concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) Fix Javascript bindings.\n",
+            "- (evm) [#2](https://github.com/evmos/evmos/pull/2) Fix `javascript` in code.\n",
+        ))
+        .expect("failed to write tmp changelog");
+
+    config::rename_in_collection(
+        &mut config.expected_spellings,
+        "Javascript".into(),
+        "JavaScript".into(),
+    )
+    .expect("failed to rename spelling");
+    changelog::apply_spelling(&config, tmp_changelog.path(), "JavaScript")
+        .expect("failed to apply spelling");
+
+    let contents = fs::read_to_string(tmp_changelog.path()).expect("failed to read changelog");
+    assert_eq!(
+        contents,
+        concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) Fix JavaScript bindings.\n",
+            "- (evm) [#2](https://github.com/evmos/evmos/pull/2) Fix `javascript` in code.\n",
+        )
+    );
+}
+
+#[test]
+fn test_rename_spelling_updates_configured_changelog() {
+    let temp_dir = assert_fs::TempDir::new().expect("failed to create temporary directory");
+
+    let mut config = load_test_config();
+    config.changelog_path = "docs/CHANGELOG.md".into();
+    config
+        .expected_spellings
+        .insert("Javascript".into(), "javascript".into());
+    config
+        .export(&temp_dir.child(".clconfig.json"))
+        .expect("failed to write config");
+
+    let contents = concat!(
+        "# Changelog\n\n",
+        "## Unreleased\n\n",
+        "### Bug Fixes\n\n",
+        "- (evm) [#1](https://github.com/evmos/evmos/pull/1) Fix Javascript bindings.\n",
+    );
+    temp_dir
+        .child("CHANGELOG.md")
+        .write_str(contents)
+        .expect("failed to write changelog");
+    temp_dir
+        .child("docs/CHANGELOG.md")
+        .write_str(contents)
+        .expect("failed to write changelog");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_clu"))
+        .args([
+            "config",
+            "spelling",
+            "rename",
+            "Javascript",
+            "JavaScript",
+            "--update-changelog",
+        ])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("failed to run config spelling rename");
+    assert!(output.status.success(), "{:?}", output);
+
+    temp_dir.child("CHANGELOG.md").assert(contents);
+    temp_dir
+        .child("docs/CHANGELOG.md")
+        .assert(contents.replace("Javascript", "JavaScript"));
+}
+
+#[test]
+fn test_toml_round_trip() {
+    let config = load_test_config();
+
+    let tmp_config = NamedTempFile::new(".clconfig.toml").expect("failed to create tmp file");
+    config
+        .export(tmp_config.path())
+        .expect("failed to export TOML config");
+
+    let contents = fs::read_to_string(tmp_config.path()).expect("failed to read config");
+    assert!(contents.contains("target_repo = \"https://github.com/evmos/evmos\""));
+
+    let loaded = config::load_from_path(tmp_config.path()).expect("failed to load TOML config");
+    assert_eq!(loaded.to_string(), config.to_string());
+}
+
+#[test]
+fn test_json_takes_precedence_over_toml() {
+    let temp_dir = assert_fs::TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".clconfig.toml")
+        .touch()
+        .expect("failed to create TOML config");
+    assert_eq!(
+        config::get_config_path(temp_dir.path()),
+        temp_dir.path().join(".clconfig.toml")
+    );
+
+    temp_dir
+        .child(".clconfig.json")
+        .touch()
+        .expect("failed to create JSON config");
+    assert_eq!(
+        config::get_config_path(temp_dir.path()),
+        temp_dir.path().join(".clconfig.json")
+    );
+}
+
+#[test]
+fn test_load_extended_config() {
+    let temp_dir = assert_fs::TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child("base.json")
+        .write_str(include_str!("testdata/evmos_config.json"))
+        .expect("failed to write base config");
+
+    fs::create_dir(temp_dir.child("package")).expect("failed to create package directory");
+    temp_dir
+        .child("package")
+        .child(".clconfig.json")
+        .write_str(r#"{ "extends": "../base.json", "categories": ["docs"] }"#)
+        .expect("failed to write child config");
+
+    let config = config::load_from_path(&temp_dir.child("package").child(".clconfig.json"))
+        .expect("failed to load extended config");
+    assert_eq!(config.extends, None);
+    assert_eq!(config.target_repo, "https://github.com/evmos/evmos");
+    assert!(config.categories.contains(&"evm".to_string()));
+    assert!(config.categories.contains(&"docs".to_string()));
+
+    let base =
+        config::read_from_path(&temp_dir.child("base.json")).expect("failed to read base config");
+    assert_eq!(config.change_types, base.change_types);
+    assert_eq!(config.commit_message, base.commit_message);
+}
+
+#[test]
+fn test_adjust_extended_config() {
+    let temp_dir = assert_fs::TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child("base.json")
+        .write_str(include_str!("testdata/evmos_config.json"))
+        .expect("failed to write base config");
+    temp_dir
+        .child(".clconfig.json")
+        .write_str(r#"{ "extends": "base.json", "categories": ["docs"] }"#)
+        .expect("failed to write child config");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_clu"))
+        .args(["config", "show", "--key", "target_repo", "--no-pager"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("failed to run config show");
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap().trim(),
+        "https://github.com/evmos/evmos"
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_clu"))
+        .args(["config", "category", "add", "ci"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("failed to run config category add");
+    assert!(output.status.success(), "{:?}", output);
+
+    let child = config::read_partial_from_path(&temp_dir.child(".clconfig.json"))
+        .expect("failed to read child config");
+    assert_eq!(child.extends, Some("base.json".to_string()));
+    assert_eq!(
+        child.categories,
+        Some(vec!["ci".to_string(), "docs".to_string()])
+    );
+    assert_eq!(child.change_types, None);
+    assert_eq!(child.target_repo, None);
+
+    let config = config::load_from_path(&temp_dir.child(".clconfig.json"))
+        .expect("failed to load extended config");
+    assert!(config.categories.contains(&"ci".to_string()));
+    assert!(config.categories.contains(&"evm".to_string()));
+}
+
+#[test]
+fn test_load_extended_toml_config() {
+    let temp_dir = assert_fs::TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child("base.json")
+        .write_str(include_str!("testdata/evmos_config.json"))
+        .expect("failed to write base config");
+    temp_dir
+        .child(".clconfig.toml")
+        .write_str("extends = \"base.json\"\ntarget_repo = \"https://github.com/evmos/os\"\n")
+        .expect("failed to write child config");
+
+    let config = config::load_from_path(&temp_dir.child(".clconfig.toml"))
+        .expect("failed to load extended config");
+    assert_eq!(config.target_repo, "https://github.com/evmos/os");
+    assert!(config.categories.contains(&"evm".to_string()));
+}
+
+#[test]
+fn test_load_cyclic_extended_config() {
+    let temp_dir = assert_fs::TempDir::new().expect("failed to create temporary directory");
+    let config = config::Config {
+        extends: Some(".clconfig.json".into()),
+        ..Default::default()
+    };
+    config
+        .export(&temp_dir.child(".clconfig.json"))
+        .expect("failed to write config");
+
+    let err = config::load_from_path(&temp_dir.child(".clconfig.json"))
+        .expect_err("expected cyclic extension");
+    assert!(matches!(err, clu::errors::ConfigError::CyclicExtends(_)));
+}
@@ -0,0 +1,51 @@
+use clu::{changelog, config, create_pr};
+use std::path::Path;
+
+#[test]
+fn test_add_entry_for_pr_uses_pr_number() {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example configuration");
+    let mut changelog =
+        changelog::parse_changelog(config.clone(), Path::new("tests/testdata/changelog_ok.md"))
+            .expect("failed to parse example changelog");
+
+    let new_entry = create_pr::add_entry_for_pr(
+        &config,
+        &mut changelog,
+        "Bug Fixes",
+        "evm",
+        "Fix the created PR link.",
+        2345,
+    )
+    .expect("failed to add entry");
+    assert_eq!(
+        new_entry.fixed,
+        "- (evm) [#2345](https://github.com/evmos/evmos/pull/2345) Fix the created PR link."
+    );
+
+    let bug_fixes = changelog.releases[0]
+        .change_types
+        .iter()
+        .find(|ct| ct.name == "Bug Fixes")
+        .expect("failed to find change type");
+    assert_eq!(bug_fixes.entries[0].pr_number, 2345);
+}
+
+#[tokio::test]
+async fn test_build_pr_request_as_draft() {
+    let client = octocrab::Octocrab::default();
+    let handler = client.pulls("evmos", "evmos");
+
+    let request = create_pr::build_pr_request(
+        &handler,
+        "fix(evm): Fix the created PR link".to_string(),
+        "feature-branch".to_string(),
+        "main".to_string(),
+        "Description.".to_string(),
+        true,
+    );
+    let serialized = serde_json::to_value(request).expect("failed to serialize request");
+    assert_eq!(serialized["draft"], true);
+    assert_eq!(serialized["head"], "feature-branch");
+    assert_eq!(serialized["base"], "main");
+}
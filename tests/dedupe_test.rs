@@ -0,0 +1,149 @@
+use clu::{changelog, config, dedupe};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_test_config() -> config::Config {
+    config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config")
+}
+
+#[cfg(test)]
+fn load_test_changelog() -> changelog::Changelog {
+    changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_duplicate_pr.md"),
+    )
+    .expect("failed to parse changelog")
+}
+
+#[test]
+fn test_dedupe_keep_first() {
+    let mut changelog = load_test_changelog();
+
+    let mut calls: Vec<(u64, usize)> = Vec::new();
+    let n_removed = dedupe::dedupe(&mut changelog, |pr, lines| {
+        calls.push((pr, lines.len()));
+        Ok(0)
+    })
+    .expect("failed to dedupe changelog");
+
+    assert_eq!(calls, vec![(1922, 3)]);
+    assert_eq!(n_removed, 2);
+
+    let unreleased = &changelog.releases[0];
+    assert_eq!(unreleased.change_types.len(), 2);
+    assert_eq!(unreleased.change_types[1].entries.len(), 1);
+    assert_eq!(unreleased.change_types[1].entries[0].pr_number, 1923);
+
+    // NOTE: the change type of the released version is removed as it has no more entries
+    assert!(changelog.releases[1].change_types.is_empty());
+}
+
+#[test]
+fn test_dedupe_keep_last() {
+    let mut changelog = load_test_changelog();
+
+    let n_removed = dedupe::dedupe(&mut changelog, |_, lines| Ok(lines.len() - 1))
+        .expect("failed to dedupe changelog");
+    assert_eq!(n_removed, 2);
+
+    let unreleased = &changelog.releases[0];
+    assert_eq!(unreleased.change_types.len(), 1);
+    assert_eq!(unreleased.change_types[0].name, "Bug Fixes");
+    assert_eq!(unreleased.change_types[0].entries[0].pr_number, 1923);
+    assert_eq!(changelog.releases[1].change_types[0].entries.len(), 1);
+}
+
+#[test]
+fn test_dedupe_no_duplicates() {
+    let mut changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_ok.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let n_removed = dedupe::dedupe(&mut changelog, |_, _| panic!("no duplicates expected"))
+        .expect("failed to dedupe changelog");
+    assert_eq!(n_removed, 0);
+}
+
+#[test]
+fn test_dedupe_entries_with_multiple_prs() {
+    let mut changelog = changelog::parse_changelog_str(
+        load_test_config(),
+        concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) Fix first.\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) [#2](https://github.com/evmos/evmos/pull/2) Fix both.\n",
+            "- (evm) [#2](https://github.com/evmos/evmos/pull/2) Fix second.\n",
+        ),
+        Path::new("CHANGELOG.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let n_removed =
+        dedupe::dedupe(&mut changelog, |_, _| Ok(0)).expect("failed to dedupe changelog");
+    assert_eq!(n_removed, 1);
+
+    let entries = &changelog.releases[0].change_types[0].entries;
+    assert_eq!(
+        entries
+            .iter()
+            .map(|e| e.pr_numbers.clone())
+            .collect::<Vec<_>>(),
+        vec![vec![1], vec![2]]
+    );
+}
+
+#[test]
+fn test_dedupe_keeps_kept_entries() {
+    let mut changelog = changelog::parse_changelog_str(
+        load_test_config(),
+        concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) Fix first.\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) [#2](https://github.com/evmos/evmos/pull/2) Fix both.\n",
+            "- (evm) [#2](https://github.com/evmos/evmos/pull/2) Fix second.\n",
+        ),
+        Path::new("CHANGELOG.md"),
+    )
+    .expect("failed to parse changelog");
+
+    // NOTE: the entry for both PRs is kept for #1, so it must not be removed for #2
+    let n_removed = dedupe::dedupe(&mut changelog, |_, lines| Ok(lines.len() - 1))
+        .expect("failed to dedupe changelog");
+    assert_eq!(n_removed, 1);
+
+    let entries = &changelog.releases[0].change_types[0].entries;
+    assert_eq!(
+        entries
+            .iter()
+            .map(|e| e.pr_numbers.clone())
+            .collect::<Vec<_>>(),
+        vec![vec![1, 2], vec![2]]
+    );
+}
+
+#[test]
+fn test_dedupe_ignores_repeated_pr_in_single_entry() {
+    let mut changelog = changelog::parse_changelog_str(
+        load_test_config(),
+        concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) [#1](https://github.com/evmos/evmos/pull/1) Fix first.\n",
+        ),
+        Path::new("CHANGELOG.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let n_removed = dedupe::dedupe(&mut changelog, |_, _| panic!("no duplicates expected"))
+        .expect("failed to dedupe changelog");
+    assert_eq!(n_removed, 0);
+    assert_eq!(changelog.releases[0].change_types[0].entries.len(), 1);
+}
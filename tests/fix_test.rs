@@ -0,0 +1,311 @@
+use assert_fs::{prelude::*, TempDir};
+use clu::{changelog, config, fix};
+use std::{convert::Infallible, fs, path::Path, process::Command};
+
+#[cfg(test)]
+fn load_changelog_to_be_fixed() -> changelog::Changelog {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+
+    changelog::parse_changelog(config, Path::new("tests/testdata/changelog_to_be_fixed.md"))
+        .expect("failed to parse changelog")
+}
+
+#[test]
+fn test_apply_all_fixes() {
+    let mut changelog = load_changelog_to_be_fixed();
+
+    let mut asked: Vec<(String, String)> = Vec::new();
+    let applied = fix::apply_fixes(&mut changelog, |line, fixed| {
+        asked.push((line.to_string(), fixed.to_string()));
+        Ok::<bool, Infallible>(true)
+    })
+    .unwrap();
+
+    assert_eq!(applied, 3);
+    assert_eq!(
+        asked.first().unwrap(),
+        &(
+            "###  state MaChine Breaking".to_string(),
+            "### State Machine Breaking".to_string()
+        )
+    );
+
+    let expected = fs::read_to_string("tests/testdata/changelog_fixed.md")
+        .expect("failed to load fixed changelog");
+    assert_eq!(changelog.get_fixed_contents().trim(), expected.trim());
+}
+
+#[test]
+fn test_skip_all_fixes() {
+    let mut changelog = load_changelog_to_be_fixed();
+
+    let applied = fix::apply_fixes(&mut changelog, |_, _| Ok::<bool, Infallible>(false)).unwrap();
+    assert_eq!(applied, 0);
+
+    let original = fs::read_to_string("tests/testdata/changelog_to_be_fixed.md")
+        .expect("failed to load changelog");
+    assert_eq!(changelog.get_fixed_contents().trim(), original.trim());
+}
+
+#[test]
+fn test_apply_only_selected_fixes() {
+    let mut changelog = load_changelog_to_be_fixed();
+
+    // NOTE: only the spelling fix in the entry is accepted
+    let applied = fix::apply_fixes(&mut changelog, |line, _| {
+        Ok::<bool, Infallible>(line.starts_with('-'))
+    })
+    .unwrap();
+    assert_eq!(applied, 1);
+
+    let contents = changelog.get_fixed_contents();
+    assert!(contents.contains("###  state MaChine Breaking"));
+    assert!(contents.contains("### API breaking"));
+    assert!(contents.contains("Deprecate legacy EIP-712 ante handler."));
+}
+
+#[test]
+fn test_check_prints_diff_without_writing() {
+    let changelog = load_changelog_to_be_fixed();
+    let original = fs::read_to_string("tests/testdata/changelog_to_be_fixed.md")
+        .expect("failed to load changelog");
+
+    let diff = fix::check(&changelog).expect("failed to check changelog");
+    assert_eq!(
+        diff,
+        vec![
+            "--- tests/testdata/changelog_to_be_fixed.md",
+            "+++ tests/testdata/changelog_to_be_fixed.md (fixed)",
+            "@@ -8 +8 @@",
+            "-###  state MaChine Breaking",
+            "+### State Machine Breaking",
+            "@@ -15 +15 @@",
+            "-### API breaking",
+            "+### API Breaking",
+            "@@ -18 +18 @@",
+            "-- (ante) [#2078](https://github.com/evmos/evmos/pull/2078) Deprecate legacy eIp-712 ante handler.",
+            "+- (ante) [#2078](https://github.com/evmos/evmos/pull/2078) Deprecate legacy EIP-712 ante handler.",
+        ]
+    );
+
+    let after = fs::read_to_string("tests/testdata/changelog_to_be_fixed.md")
+        .expect("failed to load changelog");
+    assert_eq!(original, after);
+}
+
+#[test]
+fn test_check_fixed_changelog() {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+    let changelog =
+        changelog::parse_changelog(config, Path::new("tests/testdata/changelog_fixed.md"))
+            .expect("failed to parse changelog");
+
+    assert!(fix::check(&changelog)
+        .expect("failed to check changelog")
+        .is_empty());
+}
+
+#[test]
+fn test_get_diff_with_added_and_removed_lines() {
+    assert_eq!(
+        fix::get_diff("a\nb\nc\n", "a\nc\nd\n"),
+        vec!["@@ -2 +2 @@", "-b", "@@ -4 +3 @@", "+d"]
+    );
+}
+
+#[test]
+fn test_fix_to_stdout() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".clconfig.json")
+        .write_str(include_str!("testdata/evmos_config.json"))
+        .expect("failed to write config");
+    temp_dir
+        .child("CHANGELOG.md")
+        .write_str(include_str!("testdata/changelog_to_be_fixed.md"))
+        .expect("failed to write changelog");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_clu"))
+        .args(["fix", "--stdout"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("failed to run fix command");
+    assert!(output.status.success());
+
+    let expected = include_str!("testdata/changelog_fixed.md");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap().trim(),
+        expected.trim()
+    );
+
+    temp_dir
+        .child("CHANGELOG.md")
+        .assert(include_str!("testdata/changelog_to_be_fixed.md"));
+}
+
+#[test]
+fn test_preserve_crlf_line_endings() {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+    let original = include_str!("testdata/changelog_fixed.md").replace('\n', "\r\n");
+
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let changelog_file = temp_dir.child("CHANGELOG.md");
+    changelog_file
+        .write_str(original.as_str())
+        .expect("failed to write changelog");
+
+    let changelog = changelog::parse_changelog(config, changelog_file.path())
+        .expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+
+    changelog
+        .write(changelog_file.path())
+        .expect("failed to write changelog");
+    let written = fs::read_to_string(changelog_file.path()).expect("failed to read changelog");
+    assert_eq!(written.trim_end(), original.trim_end());
+    assert!(!written.replace("\r\n", "").contains('\n'));
+}
+
+#[test]
+fn test_preserve_byte_order_mark() {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+    let changelog_path = Path::new("tests/testdata/changelog_bom.md");
+
+    let changelog =
+        changelog::parse_changelog(config, changelog_path).expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+    assert_eq!(changelog.releases.len(), 3);
+
+    let original = fs::read_to_string(changelog_path).expect("failed to read changelog");
+    assert!(original.starts_with('\u{feff}'));
+    assert_eq!(
+        changelog.get_fixed_contents().trim_end(),
+        original.trim_end()
+    );
+}
+
+#[test]
+fn test_preserve_sub_items() {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+    let changelog_path = Path::new("tests/testdata/changelog_sub_items.md");
+
+    let changelog =
+        changelog::parse_changelog(config, changelog_path).expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+
+    let entries = &changelog.releases[0].change_types[0].entries;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        entries[0].sub_items,
+        vec![
+            "  - Add the precompile contract.",
+            "  - Register it in the EVM keeper."
+        ]
+    );
+    assert!(entries[1].sub_items.is_empty());
+
+    let original = fs::read_to_string(changelog_path).expect("failed to read changelog");
+    assert_eq!(changelog.get_fixed_contents(), original);
+}
+
+#[test]
+fn test_preserve_reference_links() {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+    let changelog_path = Path::new("tests/testdata/changelog_reference_links.md");
+
+    let changelog =
+        changelog::parse_changelog(config, changelog_path).expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+
+    let entries = &changelog.releases[0].change_types[0].entries;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].pr_number, 1922);
+
+    let original = fs::read_to_string(changelog_path).expect("failed to read changelog");
+    assert_eq!(changelog.get_fixed_contents(), original);
+}
+
+#[test]
+fn test_add_missing_release_dates() {
+    let mut config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config");
+    config.lenient_releases = true;
+
+    let mut changelog = changelog::parse_changelog(
+        config,
+        Path::new("tests/testdata/changelog_incomplete_releases.md"),
+    )
+    .expect("failed to parse changelog");
+
+    assert_eq!(
+        fix::add_missing_release_dates(&mut changelog, "2023-10-31"),
+        2
+    );
+    assert_eq!(changelog.releases[0].fixed, "## Unreleased");
+    assert_eq!(
+        changelog.releases[1].fixed,
+        "## [v15.0.0](https://github.com/evmos/evmos/releases/tag/v15.0.0) - 2023-10-31"
+    );
+    assert_eq!(
+        changelog.releases[2].fixed,
+        "## [v14.0.0](https://github.com/evmos/evmos/releases/tag/v14.0.0) - 2023-10-31"
+    );
+    assert_eq!(
+        changelog.releases[3].fixed,
+        "## [v13.0.0](https://github.com/evmos/evmos/releases/tag/v13.0.0) - 2023-06-01"
+    );
+}
+
+#[test]
+fn test_fix_refuses_conflict_markers() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".clconfig.json")
+        .write_str(include_str!("testdata/evmos_config.json"))
+        .expect("failed to write config");
+    temp_dir
+        .child("CHANGELOG.md")
+        .write_str(include_str!("testdata/changelog_conflict.md"))
+        .expect("failed to write changelog");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_clu"))
+        .arg("fix")
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("failed to run fix command");
+    assert!(!output.status.success());
+
+    temp_dir
+        .child("CHANGELOG.md")
+        .assert(include_str!("testdata/changelog_conflict.md"));
+}
+
+#[test]
+fn test_fix_keeps_lines_before_first_release() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".clconfig.json")
+        .write_str(include_str!("testdata/evmos_config.json"))
+        .expect("failed to write config");
+    temp_dir
+        .child("CHANGELOG.md")
+        .write_str(include_str!("testdata/changelog_before_release.md"))
+        .expect("failed to write changelog");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_clu"))
+        .arg("fix")
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("failed to run fix command");
+    assert!(output.status.success());
+
+    temp_dir
+        .child("CHANGELOG.md")
+        .assert(include_str!("testdata/changelog_before_release.md"));
+}
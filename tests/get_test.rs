@@ -0,0 +1,164 @@
+use clu::{
+    changelog, config,
+    errors::GetError,
+    get::{self, FormatOptions, ReleaseFormat},
+};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_changelog(path: &str) -> changelog::Changelog {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example configuration");
+
+    changelog::parse_changelog(config, Path::new(path)).expect("failed to parse example changelog")
+}
+
+#[cfg(test)]
+fn load_example_changelog() -> changelog::Changelog {
+    load_changelog("tests/testdata/changelog_release_range.md")
+}
+
+#[test]
+fn test_get_release() {
+    let changelog = load_example_changelog();
+
+    let contents = get::get_release(&changelog, "v5.0.0", &FormatOptions::default())
+        .expect("failed to get release");
+    assert_eq!(
+        contents,
+        concat!(
+            "## [v5.0.0](https://github.com/evmos/evmos/releases/tag/v5.0.0) - 2023-05-01\n",
+            "\n",
+            "### Bug Fixes\n",
+            "\n",
+            "- (evm) [#8](https://github.com/evmos/evmos/pull/8) Fix problem in v5.\n",
+        )
+    );
+}
+
+#[test]
+fn test_get_release_not_found() {
+    let changelog = load_example_changelog();
+
+    let err = get::get_release(&changelog, "v1.0.0", &FormatOptions::default())
+        .expect_err("expected missing release");
+    assert!(matches!(err, GetError::VersionNotFound(v) if v == "v1.0.0"));
+}
+
+#[test]
+fn test_get_releases_since() {
+    let changelog = load_example_changelog();
+
+    let contents = get::get_releases_since(&changelog, "v4.0.0", &FormatOptions::default())
+        .expect("failed to get releases");
+    assert!(contents.starts_with("## [v6.0.0]"));
+    assert!(contents.contains("Fix problem in v5."));
+    assert!(contents.find("## [v6.0.0]") < contents.find("## [v5.0.0]"));
+    assert!(!contents.contains("Unreleased"));
+    assert!(!contents.contains("## [v4.0.0]"));
+    assert!(!contents.contains("## [v3.0.0]"));
+}
+
+#[test]
+fn test_get_releases_since_invalid_version() {
+    let changelog = load_example_changelog();
+
+    let err = get::get_releases_since(&changelog, "4.0", &FormatOptions::default())
+        .expect_err("expected invalid version");
+    assert!(matches!(err, GetError::InvalidVersion(_)));
+}
+
+#[test]
+fn test_get_unreleased() {
+    let changelog = load_example_changelog();
+
+    let expected = concat!(
+        "## Unreleased\n",
+        "\n",
+        "### Bug Fixes\n",
+        "\n",
+        "- (evm) [#10](https://github.com/evmos/evmos/pull/10) Fix unreleased problem.\n",
+    );
+    assert_eq!(
+        get::get_unreleased(&changelog, &FormatOptions::default())
+            .expect("failed to get unreleased section"),
+        expected
+    );
+    for literal in ["unreleased", "Unreleased", "UNRELEASED"] {
+        assert_eq!(
+            get::get_release(&changelog, literal, &FormatOptions::default())
+                .expect("failed to get unreleased section"),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_get_unreleased_not_found() {
+    let changelog = load_changelog("tests/testdata/changelog_no_unreleased.md");
+
+    let err = get::get_unreleased(&changelog, &FormatOptions::default())
+        .expect_err("expected missing unreleased section");
+    assert!(matches!(err, GetError::VersionNotFound(v) if v == "Unreleased"));
+
+    let err = get::get_release(&changelog, "unreleased", &FormatOptions::default())
+        .expect_err("expected missing unreleased section");
+    assert!(matches!(err, GetError::VersionNotFound(v) if v == "Unreleased"));
+}
+
+#[test]
+fn test_get_release_notes() {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example configuration");
+    let changelog = changelog::parse_changelog_str(
+        config,
+        concat!(
+            "# Changelog\n\n",
+            "## [v6.0.0](https://github.com/evmos/evmos/releases/tag/v6.0.0) - 2023-06-01\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#9](https://github.com/evmos/evmos/pull/9) Fix problem in v6.\n\n",
+            "### Improvements\n\n",
+            "- (vesting) [#8](https://github.com/evmos/evmos/pull/8) Improve vesting.\n",
+        ),
+        Path::new("CHANGELOG.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let mut options = FormatOptions {
+        format: ReleaseFormat::ReleaseNotes,
+        strip_categories: false,
+    };
+    assert_eq!(
+        get::get_release(&changelog, "v6.0.0", &options).expect("failed to get release notes"),
+        concat!(
+            "### Bug Fixes\n\n",
+            "- (evm) [#9](https://github.com/evmos/evmos/pull/9) Fix problem in v6.\n\n",
+            "### Improvements\n\n",
+            "- (vesting) [#8](https://github.com/evmos/evmos/pull/8) Improve vesting.\n",
+        )
+    );
+
+    options.strip_categories = true;
+    assert_eq!(
+        get::get_release(&changelog, "v6.0.0", &options).expect("failed to get release notes"),
+        concat!(
+            "### Bug Fixes\n\n",
+            "- [#9](https://github.com/evmos/evmos/pull/9) Fix problem in v6.\n\n",
+            "### Improvements\n\n",
+            "- [#8](https://github.com/evmos/evmos/pull/8) Improve vesting.\n",
+        )
+    );
+}
+
+#[test]
+fn test_serialize_changelog() {
+    let changelog = load_changelog("tests/testdata/changelog_ok.md");
+
+    let serialized: serde_json::Value =
+        serde_json::to_value(&changelog).expect("failed to serialize changelog");
+    assert_eq!(serialized["releases"].as_array().unwrap().len(), 3);
+    assert_eq!(serialized["releases"][0]["version"], "Unreleased");
+    assert!(serialized["releases"][0]["change_types"][0]["entries"][0]["pr_number"].is_number());
+    assert!(serialized.get("comments").is_none());
+    assert!(serialized.get("legacy_contents").is_none());
+}
@@ -1,5 +1,5 @@
 use assert_fs::{prelude::*, TempDir};
-use clu::{config, errors::InitError, init};
+use clu::{config, errors::InitError, init, init_ci};
 use predicates::prelude::*;
 use std::{collections::BTreeMap, fs};
 
@@ -8,7 +8,7 @@ fn test_init_empty_folder() {
     let temp_dir = TempDir::new().expect("failed to create temporary directory");
 
     assert!(
-        init::init_in_folder(temp_dir.path().to_path_buf()).is_ok(),
+        init::init_in_folder(temp_dir.path().to_path_buf(), None).is_ok(),
         "failed to initialize in empty folder."
     );
 
@@ -36,7 +36,7 @@ fn test_init_changelog_exists() {
     .is_ok());
 
     assert!(
-        init::init_in_folder(temp_dir.path().to_path_buf()).is_ok(),
+        init::init_in_folder(temp_dir.path().to_path_buf(), None).is_ok(),
         "failed to initialize with existing changelog"
     );
 
@@ -97,7 +97,7 @@ fn test_init_changelog_and_config_exists() {
         .touch()
         .expect("failed to create dummy config");
 
-    let res = init::init_in_folder(temp_dir.path().to_path_buf());
+    let res = init::init_in_folder(temp_dir.path().to_path_buf(), None);
     assert!(
         res.is_err(),
         "expected failure trying to initialize with config already existing"
@@ -107,3 +107,82 @@ fn test_init_changelog_and_config_exists() {
         InitError::ConfigAlreadyFound.to_string()
     )
 }
+
+#[test]
+fn test_init_keepachangelog_preset() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+    init::init_in_folder(
+        temp_dir.path().to_path_buf(),
+        Some(init::Preset::KeepAChangelog),
+    )
+    .expect("failed to initialize with preset");
+
+    let config = config::unpack_config(
+        fs::read_to_string(temp_dir.child(".clconfig.json"))
+            .expect("failed to read config")
+            .as_str(),
+    )
+    .expect("failed to unpack config");
+
+    let mut expected_change_types: BTreeMap<String, String> = BTreeMap::new();
+    expected_change_types.insert("Added".into(), "add".into());
+    expected_change_types.insert("Changed".into(), "change".into());
+    expected_change_types.insert("Deprecated".into(), "deprecate".into());
+    expected_change_types.insert("Fixed".into(), "fix".into());
+    expected_change_types.insert("Removed".into(), "remove".into());
+    expected_change_types.insert("Security".into(), "security".into());
+
+    assert_eq!(config.change_types, expected_change_types);
+}
+
+#[test]
+fn test_init_ci_github() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+    let path = init_ci::init_ci_in_folder(
+        temp_dir.path(),
+        init_ci::CIProvider::GitHub,
+        Some("https://github.com/MalteHerrmann/changelog-utils"),
+    )
+    .expect("failed to create workflow");
+    assert_eq!(
+        path,
+        temp_dir.child(".github/workflows/changelog.yml").path()
+    );
+
+    let workflow = fs::read_to_string(path).expect("failed to read workflow");
+    assert!(workflow.contains("on:\n  pull_request:"));
+    assert!(workflow.contains("run: clu lint"));
+    assert!(
+        workflow.contains("# Target repository: https://github.com/MalteHerrmann/changelog-utils")
+    );
+}
+
+#[test]
+fn test_init_ci_gitlab() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+    let path = init_ci::init_ci_in_folder(temp_dir.path(), init_ci::CIProvider::GitLab, None)
+        .expect("failed to create workflow");
+    assert_eq!(path, temp_dir.child(".gitlab-ci.yml").path());
+
+    let workflow = fs::read_to_string(path).expect("failed to read workflow");
+    assert!(workflow.contains("merge_request_event"));
+    assert!(workflow.contains("- clu lint"));
+    assert!(!workflow.contains("Target repository"));
+}
+
+#[test]
+fn test_init_ci_workflow_exists() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".gitlab-ci.yml")
+        .touch()
+        .expect("failed to create dummy workflow");
+
+    assert!(matches!(
+        init_ci::init_ci_in_folder(temp_dir.path(), init_ci::CIProvider::GitLab, None),
+        Err(InitError::WorkflowAlreadyFound(_))
+    ));
+}
@@ -0,0 +1,28 @@
+use clu::{config, learn_categories};
+
+#[cfg(test)]
+fn load_test_config() -> config::Config {
+    config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config")
+}
+
+#[test]
+fn test_get_new_categories() {
+    let titles: Vec<String> = vec![
+        "fix(cli): fix flag parsing",
+        "feat(Bank): add new query",
+        "feat(bank)!: change denomination",
+        "chore: bump dependencies",
+        "fix(evm): fix gas estimation",
+        "imp(bank): improve performance",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+
+    let new_categories = learn_categories::get_new_categories(&load_test_config(), &titles);
+    assert_eq!(
+        new_categories.into_iter().collect::<Vec<(String, usize)>>(),
+        vec![("bank".to_string(), 3), ("cli".to_string(), 1)]
+    );
+}
@@ -1,4 +1,8 @@
-use clu::{changelog, config};
+use assert_fs::{prelude::*, NamedTempFile};
+use clu::{
+    changelog, config, lint,
+    problem::{Problem, Severity},
+};
 use std::{fs, path::Path};
 
 #[cfg(test)]
@@ -15,7 +19,7 @@ fn it_should_pass_for_correct_changelogs() {
     assert_eq!(changelog.releases.len(), 3);
     assert!(changelog.problems.is_empty());
 
-    let first_release = changelog.releases.get(0).unwrap();
+    let first_release = changelog.releases.first().unwrap();
     assert_eq!(first_release.change_types.len(), 4);
     assert_eq!(first_release.change_types.first().unwrap().entries.len(), 4);
 }
@@ -42,6 +46,36 @@ fn it_should_pass_for_incorrect_changelogs_that_has_no_critical_flaws() {
     );
 }
 
+#[test]
+fn it_should_fail_to_parse_incomplete_releases_by_default() {
+    let changelog_path = Path::new("tests/testdata/changelog_incomplete_releases.md");
+    assert!(changelog::parse_changelog(load_test_config(), changelog_path).is_err());
+}
+
+#[test]
+fn it_should_report_incomplete_releases_in_lenient_mode() {
+    let mut config = load_test_config();
+    config.lenient_releases = true;
+
+    let changelog_path = Path::new("tests/testdata/changelog_incomplete_releases.md");
+    let changelog = changelog::parse_changelog(config, changelog_path)
+        .expect("failed to parse changelog with incomplete releases");
+    assert_eq!(changelog.releases.len(), 4);
+    assert_eq!(
+        changelog.problems,
+        vec![
+            "tests/testdata/changelog_incomplete_releases.md:9: Release link is missing for version v15.0.0",
+            "tests/testdata/changelog_incomplete_releases.md:9: release v15.0.0 is missing a date",
+            "tests/testdata/changelog_incomplete_releases.md:15: release v14.0.0 is missing a date",
+            "tests/testdata/changelog_incomplete_releases.md:21: Release link is missing for version v13.0.0",
+        ]
+    );
+    assert_eq!(
+        changelog.releases[1].fixed,
+        "## [v15.0.0](https://github.com/evmos/evmos/releases/tag/v15.0.0)"
+    );
+}
+
 #[test]
 fn it_should_fix_the_changelog_as_expected() {
     let incorrect_changelog = Path::new("tests/testdata/changelog_to_be_fixed.md");
@@ -57,3 +91,384 @@ fn it_should_fix_the_changelog_as_expected() {
         "expected different fixed changelog"
     );
 }
+
+#[test]
+fn it_should_truncate_the_problem_output() {
+    let incorrect_changelog = Path::new("tests/testdata/changelog_fail.md");
+    let changelog = changelog::parse_changelog(load_test_config(), incorrect_changelog)
+        .expect("failed to parse incorrect changelog");
+    assert_eq!(changelog.problems.len(), 9);
+
+    let output = lint::get_problem_output(&changelog.problems, Some(3), false);
+    assert_eq!(output.len(), 4);
+    assert_eq!(changelog.problems[..3], output[..3]);
+    assert_eq!(output[3], "... and 6 more");
+
+    let output = lint::get_problem_output(&changelog.problems, None, false);
+    assert_eq!(changelog.problems, output);
+}
+
+#[test]
+fn it_should_group_the_problems_by_file() {
+    let problems: Vec<String> = vec![
+        "entries/b.md:3: PR description should end with a dot: 'Test'".into(),
+        "entries/a.md:12: duplicate PR: #2".into(),
+        "entries/b.md:1: invalid change category: (invalid)".into(),
+        "entries/a.md:2: 'API' should be used instead of 'api'".into(),
+    ];
+
+    assert_eq!(
+        lint::get_problem_output(&problems, None, true),
+        vec![
+            "entries/a.md:",
+            "  2: 'API' should be used instead of 'api'",
+            "  12: duplicate PR: #2",
+            "entries/b.md:",
+            "  1: invalid change category: (invalid)",
+            "  3: PR description should end with a dot: 'Test'",
+        ]
+    );
+
+    assert_eq!(
+        lint::get_problem_output(&problems, Some(2), true),
+        vec![
+            "entries/a.md:",
+            "  12: duplicate PR: #2",
+            "entries/b.md:",
+            "  3: PR description should end with a dot: 'Test'",
+            "... and 2 more",
+        ]
+    );
+}
+
+#[test]
+fn it_should_detect_duplicates_in_additional_pr_links() {
+    let tmp_changelog = NamedTempFile::new("CHANGELOG.md").expect("failed to create tmp file");
+    tmp_changelog
+        .write_str(concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) Fix bug.\n",
+            "- (evm) [#2](https://github.com/evmos/evmos/pull/2) ",
+            "[#1](https://github.com/evmos/evmos/pull/1) Fix another bug.\n",
+        ))
+        .expect("failed to write tmp changelog");
+
+    let changelog = changelog::parse_changelog(load_test_config(), tmp_changelog.path())
+        .expect("failed to parse changelog");
+    assert_eq!(changelog.problems.len(), 1);
+    assert!(changelog.problems[0]
+        .message
+        .ends_with(":8: duplicate PR: #1"));
+}
+
+#[test]
+fn it_should_only_report_problems_in_the_release_range() {
+    let changelog_path = Path::new("tests/testdata/changelog_release_range.md");
+    let changelog = changelog::parse_changelog(load_test_config(), changelog_path)
+        .expect("failed to parse changelog");
+    assert_eq!(changelog.problems.len(), 5);
+
+    let problems = lint::filter_problems_by_release_range(
+        &changelog,
+        &changelog.problems,
+        Some("v4.0.0"),
+        Some("v5.0.0"),
+    )
+    .expect("failed to filter problems");
+    assert_eq!(
+        problems,
+        vec![
+            "tests/testdata/changelog_release_range.md:19: PR description should end with a dot: 'Fix problem in v5'",
+            "tests/testdata/changelog_release_range.md:25: PR description should end with a dot: 'Fix problem in v4'",
+        ]
+    );
+}
+
+#[test]
+fn it_should_include_unreleased_without_upper_bound() {
+    let changelog_path = Path::new("tests/testdata/changelog_release_range.md");
+    let changelog = changelog::parse_changelog(load_test_config(), changelog_path)
+        .expect("failed to parse changelog");
+
+    let problems = lint::filter_problems_by_release_range(
+        &changelog,
+        &changelog.problems,
+        Some("v6.0.0"),
+        None,
+    )
+    .expect("failed to filter problems");
+    assert_eq!(problems.len(), 2);
+    assert!(problems[0].message.ends_with("'Fix unreleased problem'"));
+    assert!(problems[1].message.ends_with("'Fix problem in v6'"));
+}
+
+#[test]
+fn it_should_skip_unparsable_releases_in_the_release_range() {
+    let mut config = load_test_config();
+    config.legacy_version = None;
+
+    let contents = include_str!("testdata/changelog_release_range.md").replace(
+        "## [v6.0.0](https://github.com/evmos/evmos/releases/tag/v6.0.0)",
+        "## [v600.0.0](https://github.com/evmos/evmos/releases/tag/v600.0.0)",
+    );
+    let changelog =
+        changelog::parse_changelog_str(config, contents.as_str(), Path::new("CHANGELOG.md"))
+            .expect("failed to parse changelog");
+
+    let problems = lint::filter_problems_by_release_range(
+        &changelog,
+        &changelog.problems,
+        Some("v5.0.0"),
+        Some("v5.0.0"),
+    )
+    .expect("failed to filter problems");
+    assert_eq!(
+        problems,
+        vec!["CHANGELOG.md:19: PR description should end with a dot: 'Fix problem in v5'"]
+    );
+}
+
+#[test]
+fn it_should_report_releases_out_of_order() {
+    let mut config = load_test_config();
+    config.legacy_version = None;
+
+    let changelog_path = Path::new("tests/testdata/changelog_out_of_order.md");
+    let changelog =
+        changelog::parse_changelog(config, changelog_path).expect("failed to parse changelog");
+    assert_eq!(
+        changelog.problems,
+        vec![
+            "tests/testdata/changelog_out_of_order.md:13: release v1.3.0 is out of order",
+            "tests/testdata/changelog_out_of_order.md:15: release v1.1.0 is out of order",
+        ]
+    );
+}
+
+#[test]
+fn it_should_report_entries_outside_of_change_types() {
+    let changelog_path = Path::new("tests/testdata/changelog_entry_outside_change_type.md");
+    let changelog = changelog::parse_changelog(load_test_config(), changelog_path)
+        .expect("failed to parse changelog");
+    assert_eq!(
+        changelog.problems,
+        vec![
+            "tests/testdata/changelog_entry_outside_change_type.md:5: entry found outside of a change type in release Unreleased",
+            "tests/testdata/changelog_entry_outside_change_type.md:13: entry found outside of a change type in release v15.0.0",
+        ]
+    );
+
+    let unreleased = changelog.releases.first().unwrap();
+    assert_eq!(unreleased.change_types.len(), 2);
+    assert_eq!(unreleased.change_types[0].name, "Improvements");
+    assert_eq!(unreleased.change_types[0].entries[0].pr_number, 1801);
+    assert_eq!(unreleased.change_types[1].entries[0].pr_number, 109);
+
+    let fixed = changelog.get_fixed_contents();
+    assert!(fixed.contains(concat!(
+        "## Unreleased\n\n### Improvements\n\n",
+        "- (evm) [#1801](https://github.com/evmos/evmos/pull/1801) Fix the problem `gas_used` is 0.\n"
+    )));
+}
+
+#[test]
+fn it_should_report_change_types_and_entries_before_releases() {
+    let changelog_path = Path::new("tests/testdata/changelog_before_release.md");
+    let changelog = changelog::parse_changelog(load_test_config(), changelog_path)
+        .expect("failed to parse changelog");
+    assert_eq!(
+        changelog.problems,
+        vec![
+            "tests/testdata/changelog_before_release.md:3: change type found before any release section",
+            "tests/testdata/changelog_before_release.md:5: entry found before any release section",
+        ]
+    );
+
+    assert_eq!(changelog.releases.len(), 1);
+    let unreleased = changelog.releases.first().unwrap();
+    assert_eq!(unreleased.change_types.len(), 1);
+    assert_eq!(unreleased.change_types[0].entries.len(), 1);
+    assert_eq!(unreleased.change_types[0].entries[0].pr_number, 109);
+}
+
+#[test]
+fn it_should_fail_for_unknown_change_types_in_closed_set() {
+    let mut config = load_test_config();
+    config.closed_change_types = true;
+
+    let incorrect_changelog = Path::new("tests/testdata/changelog_fail.md");
+    let err = changelog::parse_changelog(config, incorrect_changelog)
+        .expect_err("expected parsing to fail");
+    assert_eq!(
+        err.to_string(),
+        "failed to parse change type: 'Invalid Category' is not a configured change type"
+    );
+}
+
+#[test]
+fn it_should_report_an_empty_unreleased_section() {
+    let mut config = load_test_config();
+    config.warn_empty_unreleased = true;
+
+    let changelog_path = Path::new("tests/testdata/changelog_empty_unreleased.md");
+    let changelog = changelog::parse_changelog(config.clone(), changelog_path)
+        .expect("failed to parse changelog");
+    assert_eq!(
+        changelog.problems,
+        vec!["tests/testdata/changelog_empty_unreleased.md:6: Unreleased section is empty"]
+    );
+
+    config.warn_empty_unreleased = false;
+    let changelog =
+        changelog::parse_changelog(config, changelog_path).expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+}
+
+#[test]
+fn it_should_not_report_an_unreleased_section_with_entries() {
+    let mut config = load_test_config();
+    config.warn_empty_unreleased = true;
+
+    let changelog_path = Path::new("tests/testdata/changelog_ok.md");
+    let changelog =
+        changelog::parse_changelog(config, changelog_path).expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+}
+
+#[test]
+fn it_should_report_and_fix_trailing_whitespace() {
+    let changelog_path = Path::new("tests/testdata/changelog_trailing_whitespace.md");
+    let changelog = changelog::parse_changelog(load_test_config(), changelog_path)
+        .expect("failed to parse changelog");
+    assert_eq!(
+        changelog.problems,
+        vec![
+            "tests/testdata/changelog_trailing_whitespace.md:1:5: trailing whitespace",
+            "tests/testdata/changelog_trailing_whitespace.md:4:12: trailing whitespace",
+            "tests/testdata/changelog_trailing_whitespace.md:6:14: trailing whitespace",
+            "tests/testdata/changelog_trailing_whitespace.md:8:14: trailing whitespace",
+            "tests/testdata/changelog_trailing_whitespace.md:9:1: trailing whitespace",
+            "tests/testdata/changelog_trailing_whitespace.md:10: PR description should not end with whitespace: 'Fix the problem `gas_used` is 0. '",
+        ]
+    );
+
+    let fixed = changelog.get_fixed_contents();
+    assert!(fixed.lines().all(|l| l.trim_end() == l), "{fixed}");
+    assert!(fixed.starts_with("<!--\nSome comments at head of file...\n-->\n# Changelog\n"));
+}
+
+#[test]
+fn it_should_pass_blank_line_checks_for_correct_changelogs() {
+    let mut config = load_test_config();
+    config.check_blank_lines = true;
+
+    let changelog = changelog::parse_changelog(config, Path::new("tests/testdata/changelog_ok.md"))
+        .expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+}
+
+#[test]
+fn it_should_report_deviating_blank_lines_if_configured() {
+    let changelog_path = Path::new("tests/testdata/changelog_blank_lines.md");
+    let changelog = changelog::parse_changelog(load_test_config(), changelog_path)
+        .expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty());
+
+    let mut config = load_test_config();
+    config.check_blank_lines = true;
+
+    let changelog =
+        changelog::parse_changelog(config, changelog_path).expect("failed to parse changelog");
+    assert_eq!(
+        changelog.problems,
+        vec![
+            "tests/testdata/changelog_blank_lines.md:6: there should be exactly one blank line after a heading",
+            "tests/testdata/changelog_blank_lines.md:8: there should be no blank lines between entries",
+            "tests/testdata/changelog_blank_lines.md:11: there should be exactly one blank line before a heading",
+            "tests/testdata/changelog_blank_lines.md:14: there should be exactly one blank line before a heading",
+        ]
+    );
+}
+
+#[test]
+fn it_should_attach_the_problem_severities() {
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_fail.md"),
+    )
+    .expect("failed to parse incorrect changelog");
+    let get_severity = |suffix: &str| {
+        changelog
+            .problems
+            .iter()
+            .find(|p| p.message.ends_with(suffix))
+            .map(|p| p.severity)
+    };
+    assert_eq!(get_severity("duplicate PR: #1862"), Some(Severity::Error));
+    assert_eq!(
+        get_severity("'ABI' should be used instead of 'ABi'"),
+        Some(Severity::Warning)
+    );
+
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_trailing_whitespace.md"),
+    )
+    .expect("failed to parse changelog");
+    assert!(!changelog.problems.is_empty());
+    assert!(changelog
+        .problems
+        .iter()
+        .all(|p| p.severity == Severity::Info));
+}
+
+#[test]
+fn it_should_not_derive_the_severity_from_the_message() {
+    let mut config = load_test_config();
+    config.forbidden_terms = vec!["invalid entry".into()];
+
+    let changelog = changelog::parse_changelog_str(
+        config,
+        concat!(
+            "# Changelog\n\n",
+            "## Unreleased\n\n",
+            "### Bug Fixes\n\n",
+            "- (evm) [#1](https://github.com/evmos/evmos/pull/1) Fix invalid entry.\n",
+        ),
+        Path::new("CHANGELOG.md"),
+    )
+    .expect("failed to parse changelog");
+    assert_eq!(
+        changelog.problems,
+        vec!["CHANGELOG.md:7: description contains forbidden term: 'invalid entry'"]
+    );
+    assert_eq!(changelog.problems[0].severity, Severity::Warning);
+}
+
+#[test]
+fn it_should_fail_depending_on_the_severity_threshold() {
+    let problems = vec![
+        Problem::warning("CHANGELOG.md:21: 'ABI' should be used instead of 'ABi'"),
+        Problem::info("CHANGELOG.md:4:12: trailing whitespace"),
+    ];
+    assert!(!lint::has_failing_problems(&problems, Severity::Error));
+    assert!(lint::has_failing_problems(&problems, Severity::Warning));
+    assert!(lint::has_failing_problems(&problems, Severity::Info));
+
+    let info_only = vec![Problem::info("CHANGELOG.md:4:12: trailing whitespace")];
+    assert!(!lint::has_failing_problems(&info_only, Severity::Error));
+    assert!(!lint::has_failing_problems(&info_only, Severity::Warning));
+    assert!(lint::has_failing_problems(&info_only, Severity::Info));
+
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_fail.md"),
+    )
+    .expect("failed to parse incorrect changelog");
+    assert!(lint::has_failing_problems(
+        &changelog.problems,
+        Severity::Error
+    ));
+}
@@ -0,0 +1,83 @@
+use clu::{
+    changelog, config,
+    errors::{MoveError, RemoveError},
+    move_entry,
+};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_example_config() -> config::Config {
+    config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example configuration")
+}
+
+#[cfg(test)]
+fn load_example_changelog(config: &config::Config) -> changelog::Changelog {
+    changelog::parse_changelog(config.clone(), Path::new("tests/testdata/changelog_ok.md"))
+        .expect("failed to parse example changelog")
+}
+
+#[test]
+fn test_pass_move_into_existing_change_type() {
+    let config = load_example_config();
+    let mut changelog = load_example_changelog(&config);
+
+    move_entry::move_entry(&config, &mut changelog, 1801, "Improvements")
+        .expect("failed to move entry");
+
+    let unreleased = changelog.releases.first().unwrap();
+    let improvements = unreleased
+        .change_types
+        .iter()
+        .find(|ct| ct.name == "Improvements")
+        .unwrap();
+    assert_eq!(improvements.entries.len(), 5);
+    assert_eq!(improvements.entries.first().unwrap().pr_number, 1801);
+
+    let bug_fixes = unreleased
+        .change_types
+        .iter()
+        .find(|ct| ct.name == "Bug Fixes")
+        .unwrap();
+    assert_eq!(bug_fixes.entries.len(), 1);
+}
+
+#[test]
+fn test_pass_move_into_new_change_type() {
+    let config = load_example_config();
+    let mut changelog = load_example_changelog(&config);
+
+    move_entry::move_entry(&config, &mut changelog, 1801, "Features")
+        .expect("failed to move entry");
+
+    let unreleased = changelog.releases.first().unwrap();
+    assert_eq!(unreleased.change_types.len(), 5);
+    let features = unreleased.change_types.last().unwrap();
+    assert_eq!(features.name, "Features");
+    assert_eq!(features.entries.len(), 1);
+    assert_eq!(features.entries.first().unwrap().pr_number, 1801);
+    assert!(changelog.get_fixed_contents().contains("### Features"));
+}
+
+#[test]
+fn test_fail_move_into_invalid_change_type() {
+    let config = load_example_config();
+    let mut changelog = load_example_changelog(&config);
+
+    let err = move_entry::move_entry(&config, &mut changelog, 1801, "Invalid")
+        .expect_err("expected move to fail");
+    assert!(matches!(err, MoveError::InvalidChangeType(ct) if ct == "Invalid"));
+}
+
+#[test]
+fn test_fail_move_unknown_pr() {
+    let config = load_example_config();
+    let mut changelog = load_example_changelog(&config);
+
+    let err = move_entry::move_entry(&config, &mut changelog, 9999, "Features")
+        .expect_err("expected move to fail");
+    assert!(matches!(
+        err,
+        MoveError::Remove(RemoveError::NotFound(9999))
+    ));
+}
@@ -0,0 +1,52 @@
+use assert_fs::{prelude::*, TempDir};
+use clu::{errors::PathError, path};
+
+#[test]
+fn test_changelog_path() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child("CHANGELOG.md")
+        .touch()
+        .expect("failed to create dummy changelog");
+
+    let got = path::get_path(temp_dir.path(), false).expect("failed to get changelog path");
+    assert!(got.is_absolute(), "expected absolute path");
+    assert_eq!(
+        got,
+        temp_dir
+            .child("CHANGELOG.md")
+            .path()
+            .canonicalize()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_config_path() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    temp_dir
+        .child(".clconfig.json")
+        .touch()
+        .expect("failed to create dummy config");
+
+    let got = path::get_path(temp_dir.path(), true).expect("failed to get config path");
+    assert!(got.is_absolute(), "expected absolute path");
+    assert_eq!(
+        got,
+        temp_dir
+            .child(".clconfig.json")
+            .path()
+            .canonicalize()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_config_path_not_found() {
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+    assert!(matches!(
+        path::get_path(temp_dir.path(), true).unwrap_err(),
+        PathError::NoConfigFound
+    ));
+}
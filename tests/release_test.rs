@@ -0,0 +1,75 @@
+use clu::{changelog, config, errors::ReleaseCLIError, release_cli};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_example_changelog() -> (config::Config, changelog::Changelog) {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example configuration");
+    let changelog = changelog::parse_changelog(
+        config.clone(),
+        Path::new("tests/testdata/changelog_release_range.md"),
+    )
+    .expect("failed to parse example changelog");
+
+    (config, changelog)
+}
+
+#[test]
+fn test_release_moves_unreleased_entries() {
+    let (config, mut changelog) = load_example_changelog();
+    assert_eq!(changelog.releases.len(), 5);
+
+    release_cli::add_release(&config, &mut changelog, "v7.0.0", "2023-07-01")
+        .expect("failed to add release");
+    assert_eq!(changelog.releases.len(), 6);
+
+    let unreleased = changelog.releases.first().unwrap();
+    assert!(unreleased.is_unreleased());
+    assert!(unreleased.change_types.is_empty());
+
+    let new_release = changelog.releases.get(1).unwrap();
+    assert_eq!(new_release.version, "v7.0.0");
+    assert_eq!(
+        new_release.fixed,
+        "## [v7.0.0](https://github.com/evmos/evmos/releases/tag/v7.0.0) - 2023-07-01"
+    );
+    assert_eq!(new_release.change_types.len(), 1);
+    assert_eq!(new_release.change_types[0].entries[0].pr_number, 10);
+
+    assert!(changelog.get_fixed_contents().contains(concat!(
+        "## Unreleased\n\n",
+        "## [v7.0.0](https://github.com/evmos/evmos/releases/tag/v7.0.0) - 2023-07-01\n\n",
+        "### Bug Fixes\n\n",
+        "- (evm) [#10](https://github.com/evmos/evmos/pull/10) Fix unreleased problem.\n",
+    )));
+}
+
+#[test]
+fn test_release_duplicate_version() {
+    let (config, mut changelog) = load_example_changelog();
+
+    let err = release_cli::add_release(&config, &mut changelog, "v6.0.0", "2023-07-01")
+        .expect_err("expected duplicate version");
+    assert!(matches!(err, ReleaseCLIError::DuplicateVersion(v) if v == "v6.0.0"));
+}
+
+#[test]
+fn test_release_version_not_greater() {
+    let (config, mut changelog) = load_example_changelog();
+
+    let err = release_cli::add_release(&config, &mut changelog, "v5.1.0", "2023-07-01")
+        .expect_err("expected version not greater than latest");
+    assert!(
+        matches!(err, ReleaseCLIError::VersionNotGreater(v, latest) if v == "v5.1.0" && latest == "v6.0.0")
+    );
+    assert_eq!(changelog.releases.len(), 5);
+}
+
+#[test]
+fn test_release_with_given_version() {
+    let (config, mut changelog) = load_example_changelog();
+
+    release_cli::add_release(&config, &mut changelog, "v6.0.1-rc1", "2023-07-01")
+        .expect("failed to add release with given version");
+    assert_eq!(changelog.releases.get(1).unwrap().version, "v6.0.1-rc1");
+}
@@ -0,0 +1,62 @@
+use clu::{changelog, config, errors::RemoveError, remove};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_example_changelog() -> changelog::Changelog {
+    let config = config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example configuration");
+
+    changelog::parse_changelog(config, Path::new("tests/testdata/changelog_ok.md"))
+        .expect("failed to parse example changelog")
+}
+
+#[test]
+fn test_pass_remove_entry() {
+    let mut changelog = load_example_changelog();
+
+    remove::remove_entry(&mut changelog, 1801).expect("failed to remove entry");
+
+    let unreleased = changelog.releases.first().unwrap();
+    let bug_fixes = unreleased
+        .change_types
+        .iter()
+        .find(|ct| ct.name == "Bug Fixes")
+        .expect("expected bug fixes to still be present");
+    assert_eq!(bug_fixes.entries.len(), 1);
+    assert_eq!(bug_fixes.entries.first().unwrap().pr_number, 109);
+    assert!(!changelog.get_fixed_contents().contains("#1801"));
+}
+
+#[test]
+fn test_pass_remove_last_entry_drops_change_type() {
+    let mut changelog = load_example_changelog();
+    assert_eq!(changelog.releases.first().unwrap().change_types.len(), 4);
+
+    remove::remove_entry(&mut changelog, 1801).expect("failed to remove entry");
+    remove::remove_entry(&mut changelog, 109).expect("failed to remove entry");
+
+    let unreleased = changelog.releases.first().unwrap();
+    assert_eq!(unreleased.change_types.len(), 3);
+    assert!(unreleased
+        .change_types
+        .iter()
+        .all(|ct| ct.name != "Bug Fixes"));
+    assert!(!changelog.get_fixed_contents().contains("### Bug Fixes"));
+}
+
+#[test]
+fn test_fail_remove_unknown_pr() {
+    let mut changelog = load_example_changelog();
+
+    let err = remove::remove_entry(&mut changelog, 9999).expect_err("expected removal to fail");
+    assert!(matches!(err, RemoveError::NotFound(9999)));
+}
+
+#[test]
+fn test_fail_remove_released_pr() {
+    let mut changelog = load_example_changelog();
+
+    // NOTE: PR 1862 is only contained in the v15.0.0 release
+    let err = remove::remove_entry(&mut changelog, 1862).expect_err("expected removal to fail");
+    assert!(matches!(err, RemoveError::NotFound(1862)));
+}
@@ -0,0 +1,89 @@
+use clu::{changelog, config, stats};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_test_config() -> config::Config {
+    config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config")
+}
+
+#[test]
+fn test_stats() {
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_ok.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let stats = stats::get_stats(&load_test_config(), &changelog);
+    assert_eq!(stats.releases, 2);
+    assert_eq!(stats.entries, 15);
+    assert_eq!(stats.entries_per_change_type.get("Bug Fixes"), Some(&2));
+    assert_eq!(stats.entries_per_change_type.get("API Breaking"), Some(&5));
+    assert_eq!(stats.entries_per_category.get("evm"), Some(&2));
+    assert_eq!(stats.oldest_release_date, Some("2021-10-31".to_string()));
+    assert_eq!(stats.newest_release_date, Some("2023-10-31".to_string()));
+    assert_eq!(stats.days_between_releases, Some(730));
+}
+
+#[test]
+fn test_stats_with_custom_date_format() {
+    let mut config = load_test_config();
+    config.date_format = Some("%d.%m.%Y".to_string());
+
+    let changelog = changelog::parse_changelog_str(
+        config.clone(),
+        concat!(
+            "# Changelog\n\n",
+            "## [v15.0.0](https://github.com/evmos/evmos/releases/tag/v15.0.0) - 31.10.2023\n\n",
+            "## [v14.0.0](https://github.com/evmos/evmos/releases/tag/v14.0.0) - 01.10.2023\n",
+        ),
+        Path::new("CHANGELOG.md"),
+    )
+    .expect("failed to parse changelog");
+    assert!(changelog.problems.is_empty(), "{:?}", changelog.problems);
+
+    let stats = stats::get_stats(&config, &changelog);
+    assert_eq!(stats.oldest_release_date, Some("01.10.2023".to_string()));
+    assert_eq!(stats.newest_release_date, Some("31.10.2023".to_string()));
+    assert_eq!(stats.days_between_releases, Some(30));
+}
+
+#[test]
+fn test_stats_json() {
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_ok.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let json: serde_json::Value =
+        serde_json::to_value(stats::get_stats(&load_test_config(), &changelog))
+            .expect("failed to serialize stats");
+    assert_eq!(json["releases"], 2);
+    assert_eq!(json["entries"], 15);
+}
+
+#[test]
+fn test_count_entries_by_author() {
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_ok.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let mut lookups: Vec<u64> = Vec::new();
+    let counts = stats::count_entries_by_author(changelog.releases.first().unwrap(), |pr| {
+        lookups.push(pr);
+        match pr {
+            1922 | 1949 => Some("alice".to_string()),
+            1801 => Some("bob".to_string()),
+            _ => None,
+        }
+    });
+
+    assert_eq!(lookups.len(), 13);
+    assert_eq!(counts.get("alice"), Some(&2));
+    assert_eq!(counts.get("bob"), Some(&1));
+    assert_eq!(counts.get("unknown"), Some(&10));
+}
@@ -0,0 +1,45 @@
+use clu::{changelog, config, verify_links};
+use std::path::Path;
+
+#[cfg(test)]
+fn load_test_config() -> config::Config {
+    config::unpack_config(include_str!("testdata/evmos_config.json"))
+        .expect("failed to load example config")
+}
+
+#[test]
+fn test_get_broken_links() {
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_ok.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let missing_pr = changelog.releases[0].change_types[0].entries[0].pr_number;
+    let broken_links = verify_links::get_broken_links(&changelog, false, |pr| pr != missing_pr);
+    assert_eq!(broken_links.len(), 1);
+    assert!(broken_links[0].contains(format!("PR #{missing_pr} not found").as_str()));
+
+    assert!(verify_links::get_broken_links(&changelog, false, |_| true).is_empty());
+}
+
+#[test]
+fn test_get_broken_links_unreleased_only() {
+    let changelog = changelog::parse_changelog(
+        load_test_config(),
+        Path::new("tests/testdata/changelog_ok.md"),
+    )
+    .expect("failed to parse changelog");
+
+    let n_unreleased: usize = changelog.releases[0]
+        .change_types
+        .iter()
+        .map(|ct| ct.entries.iter().map(|e| e.pr_numbers.len()).sum::<usize>())
+        .sum();
+
+    assert_eq!(
+        verify_links::get_broken_links(&changelog, true, |_| false).len(),
+        n_unreleased
+    );
+    assert!(verify_links::get_broken_links(&changelog, false, |_| false).len() > n_unreleased);
+}